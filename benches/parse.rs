@@ -0,0 +1,44 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use interim::{civil, parse_date_string, Dialect, Expression};
+
+const INPUTS: &[&str] = &[
+    "next monday 9am",
+    "1 month",
+    "2017-06-30 08:20:30 +04:00",
+    "last 8/11 9am",
+];
+
+fn base() -> civil::DateTime {
+    let date = civil::Date::new(2018, 3, 21).unwrap();
+    let time = civil::Time::new(11, 0, 0).unwrap();
+    civil::DateTime::from_parts(date, time, 7200).unwrap()
+}
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    let now = base();
+
+    for input in INPUTS {
+        group.bench_with_input(
+            BenchmarkId::new("parse_date_string", input),
+            input,
+            |b, input| {
+                b.iter(|| parse_date_string(input, now, Dialect::Uk));
+            },
+        );
+
+        let expression = Expression::parse(input, Dialect::Uk).unwrap();
+        group.bench_with_input(
+            BenchmarkId::new("evaluate", input),
+            &expression,
+            |b, expression| {
+                b.iter(|| expression.evaluate(now));
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
@@ -11,7 +11,8 @@ pub fn main() {
             parse_date_string(arg.as_str(), OffsetDateTime::now_utc(), Dialect::Us).unwrap()
         );
     }
-    #[cfg(feature = "chrono_0_4")]
+    // chrono's `Local` needs std
+    #[cfg(all(feature = "chrono_0_4", feature = "std"))]
     {
         use chrono::Local;
         use interim::{parse_date_string, Dialect};
@@ -20,7 +21,7 @@ pub fn main() {
             parse_date_string(arg.as_str(), Local::now(), Dialect::Us).unwrap()
         );
     }
-    #[cfg(not(any(feature = "time_0_3", feature = "chrono_0_4")))]
+    #[cfg(not(any(feature = "time_0_3", all(feature = "chrono_0_4", feature = "std"))))]
     {
         eprintln!("Please enable either time or chrono feature")
     }
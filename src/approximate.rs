@@ -0,0 +1,261 @@
+use core::fmt;
+
+use crate::{Difference, Interval, Unit};
+
+const MINUTE: u64 = 60;
+const HOUR: u64 = 3600;
+const DAY: u64 = 86400;
+// the average lengths in the Gregorian calendar
+const MONTH: u64 = 2_629_746;
+const YEAR: u64 = 31_556_952;
+
+/// Options for [`Interval::approximate_with`] and [`Difference::approximate_with`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ApproximateOptions {
+    few_seconds: u64,
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    two_units: bool,
+}
+
+impl ApproximateOptions {
+    /// Under 45 seconds is "a few seconds", then up to 44 minutes, 21 hours, 25 days and
+    /// 10 months are written in those units, and anything longer in years
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            few_seconds: 45,
+            minutes: 45,
+            hours: 22,
+            days: 26,
+            months: 11,
+            two_units: false,
+        }
+    }
+
+    /// Durations of less than this many seconds are "a few seconds". Defaults to 45.
+    /// Below that, durations that round to less than a minute are written in seconds,
+    /// like "0 seconds" or "20 seconds" with 0.
+    #[must_use]
+    pub const fn few_seconds(mut self, seconds: u64) -> Self {
+        self.few_seconds = seconds;
+        self
+    }
+
+    /// Durations that round to less than this many minutes are written in minutes,
+    /// and longer ones in hours or more. Defaults to 45.
+    #[must_use]
+    pub const fn minutes(mut self, minutes: u64) -> Self {
+        self.minutes = minutes;
+        self
+    }
+
+    /// Durations that round to less than this many hours are written in hours,
+    /// and longer ones in days or more. Defaults to 22.
+    #[must_use]
+    pub const fn hours(mut self, hours: u64) -> Self {
+        self.hours = hours;
+        self
+    }
+
+    /// Durations that round to less than this many days are written in days,
+    /// and longer ones in months or years. Defaults to 26.
+    #[must_use]
+    pub const fn days(mut self, days: u64) -> Self {
+        self.days = days;
+        self
+    }
+
+    /// Durations that round to less than this many months are written in months,
+    /// and longer ones in years. Defaults to 11.
+    #[must_use]
+    pub const fn months(mut self, months: u64) -> Self {
+        self.months = months;
+        self
+    }
+
+    /// Write the two most significant units, like "2 days 3 hours", rather than rounding
+    /// to one, like "2 days"
+    #[must_use]
+    pub const fn two_units(mut self, enabled: bool) -> Self {
+        self.two_units = enabled;
+        self
+    }
+}
+
+impl Default for ApproximateOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A duration rounded to its most significant units, from [`Interval::approximate`] or
+/// [`Difference::approximate`]. `Display` writes it, like "about an hour" or "3 days ago".
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Approximate {
+    // `None` for a few seconds
+    first: Option<(u64, Unit)>,
+    second: Option<(u64, Unit)>,
+    negative: bool,
+}
+
+/// Hours, months and years are written with "about", as in "about an hour" and "about 3 years",
+/// and other units without it, as in "a day" and "5 minutes". Negative durations end with "ago".
+impl fmt::Display for Approximate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = |unit| match unit {
+            Unit::Second => "second",
+            Unit::Minute => "minute",
+            Unit::Hour => "hour",
+            Unit::Day => "day",
+            Unit::Month => "month",
+            // weeks are never chosen
+            _ => "year",
+        };
+
+        match self.first {
+            None => f.write_str("a few seconds")?,
+            Some((count, unit)) => {
+                if matches!(unit, Unit::Hour | Unit::Month | Unit::Year) {
+                    f.write_str("about ")?;
+                }
+                match (count, unit, self.second) {
+                    (1, Unit::Hour, None) => f.write_str("an hour")?,
+                    (1, unit, None) => write!(f, "a {}", name(unit))?,
+                    (1, unit, Some(_)) => write!(f, "1 {}", name(unit))?,
+                    (count, unit, _) => write!(f, "{count} {}s", name(unit))?,
+                }
+            }
+        }
+        if let Some((count, unit)) = self.second {
+            let plural = if count == 1 { "" } else { "s" };
+            write!(f, " {count} {}{plural}", name(unit))?;
+        }
+        if self.negative {
+            f.write_str(" ago")?;
+        }
+        Ok(())
+    }
+}
+
+impl Interval {
+    /// The interval rounded to its most significant unit, like "about an hour" for 45 minutes,
+    /// or "a day" for 26 hours. Months and years are counted with their average lengths.
+    ///
+    /// ```
+    /// use interim::Interval;
+    ///
+    /// assert_eq!(Interval::Seconds(30).approximate().to_string(), "a few seconds");
+    /// assert_eq!(Interval::Seconds(45 * 60).approximate().to_string(), "about an hour");
+    /// assert_eq!(Interval::Seconds(26 * 3600).approximate().to_string(), "a day");
+    /// assert_eq!(Interval::Days(-45).approximate().to_string(), "about a month ago");
+    /// assert_eq!(Interval::Months(20).approximate().to_string(), "about 2 years");
+    /// ```
+    #[must_use]
+    pub fn approximate(&self) -> Approximate {
+        self.approximate_with(ApproximateOptions::new())
+    }
+
+    /// The interval rounded like [`Interval::approximate`], with the thresholds for each unit
+    /// and the number of units given by the options
+    #[must_use]
+    pub fn approximate_with(&self, options: ApproximateOptions) -> Approximate {
+        approximate(length(self), options)
+    }
+}
+
+impl Difference {
+    /// The difference rounded to its most significant unit, like [`Interval::approximate`]
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use interim::{difference, ApproximateOptions};
+    ///
+    /// let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+    /// let end = Utc.with_ymd_and_hms(2024, 1, 3, 12, 40, 0).unwrap();
+    /// let difference = difference(&start, &end).unwrap();
+    /// assert_eq!(difference.approximate().to_string(), "2 days");
+    ///
+    /// let options = ApproximateOptions::new().two_units(true);
+    /// assert_eq!(difference.approximate_with(options).to_string(), "2 days 4 hours");
+    /// ```
+    #[must_use]
+    pub fn approximate(&self) -> Approximate {
+        self.approximate_with(ApproximateOptions::new())
+    }
+
+    /// The difference rounded like [`Interval::approximate_with`]
+    #[must_use]
+    pub fn approximate_with(&self, options: ApproximateOptions) -> Approximate {
+        let total = self.intervals().iter().map(length).sum();
+        approximate(total, options)
+    }
+}
+
+// the length of an interval, with months of average length
+fn length(interval: &Interval) -> i128 {
+    match *interval {
+        Interval::Seconds(n) => i128::from(n),
+        Interval::Days(n) => i128::from(n) * i128::from(DAY),
+        Interval::Months(n) => i128::from(n) * i128::from(MONTH),
+    }
+}
+
+fn approximate(seconds: i128, options: ApproximateOptions) -> Approximate {
+    let negative = seconds < 0;
+    let secs = seconds.unsigned_abs();
+    let rounded = |size: u64| (secs + u128::from(size / 2)) / u128::from(size);
+
+    let unit = if secs < u128::from(options.few_seconds) {
+        return Approximate {
+            first: None,
+            second: None,
+            negative,
+        };
+    } else if rounded(MINUTE) == 0 {
+        Unit::Second
+    } else if rounded(MINUTE) < u128::from(options.minutes) {
+        Unit::Minute
+    } else if rounded(HOUR) < u128::from(options.hours) {
+        Unit::Hour
+    } else if rounded(DAY) < u128::from(options.days) {
+        Unit::Day
+    } else if rounded(MONTH) < u128::from(options.months) {
+        Unit::Month
+    } else {
+        Unit::Year
+    };
+
+    // the unit and the next one down
+    let (size, next_size, next) = match unit {
+        Unit::Second => (1, 1, Unit::Second),
+        Unit::Minute => (MINUTE, 1, Unit::Second),
+        Unit::Hour => (HOUR, MINUTE, Unit::Minute),
+        Unit::Day => (DAY, HOUR, Unit::Hour),
+        Unit::Month => (MONTH, DAY, Unit::Day),
+        _ => (YEAR, MONTH, Unit::Month),
+    };
+    let mut first = secs / u128::from(size);
+    let mut second = 0;
+    if options.two_units && first > 0 {
+        let rest = secs % u128::from(size);
+        second = (rest + u128::from(next_size / 2)) / u128::from(next_size);
+        // rounded up to a whole unit, like 23.6 hours
+        if second * u128::from(next_size) >= u128::from(size) {
+            first += 1;
+            second = 0;
+        }
+    } else {
+        first = rounded(size);
+    }
+    let count = |n: u128| u64::try_from(n).unwrap_or(u64::MAX);
+    Approximate {
+        // only zero is 0, and a unit longer than the duration, like an hour for a minute
+        // with `minutes(0)`, is 1
+        first: Some((if secs == 0 { 0 } else { count(first).max(1) }, unit)),
+        second: (second != 0).then(|| (count(second), next)),
+        negative,
+    }
+}
@@ -0,0 +1,241 @@
+//! The parse tree of an [`Expression`](crate::Expression).
+//!
+//! This describes what the text says without evaluating it against a base time,
+//! so it can be inspected: whether the date is absolute or relative, whether a time
+//! or timezone was given, and where in the text each part was found.
+//!
+//! ```
+//! use interim::ast::{DateKind, Direction, Name};
+//! use interim::{Dialect, Expression};
+//!
+//! let text = "next friday 8pm EST";
+//! let expression = Expression::parse(text, Dialect::Uk).unwrap();
+//! let ast = expression.ast();
+//!
+//! let date = ast.date().unwrap();
+//! assert_eq!(date.kind(), DateKind::Named { name: Name::Weekday(4), direction: Direction::Next });
+//! assert_eq!(&text[date.span()], "next friday");
+//!
+//! let time = ast.time().unwrap();
+//! assert_eq!((time.hour(), time.minute()), (20, 0));
+//! assert_eq!(&text[time.span()], "8pm");
+//! assert_eq!(time.zone().unwrap().fixed_offset(), Some(-5 * 3600));
+//! assert!(ast.convert().is_none());
+//! ```
+
+use core::ops::Range;
+
+use crate::types::{ByName, DateSpec, DateTimeSpec, TimeSpec};
+use crate::Interval;
+
+/// A byte range in the parsed text
+pub type Span = Range<usize>;
+
+/// A parsed date-time expression
+#[derive(Clone, Copy, Debug)]
+pub struct Ast<'a> {
+    spec: &'a DateTimeSpec,
+}
+
+impl<'a> Ast<'a> {
+    pub(crate) fn new(spec: &'a DateTimeSpec) -> Self {
+        Self { spec }
+    }
+
+    /// The date, if one was given
+    #[must_use]
+    pub fn date(self) -> Option<Date<'a>> {
+        Some(Date {
+            spec: self.spec.date.as_ref()?,
+            ast: self,
+        })
+    }
+
+    /// The time of day, if one was given
+    #[must_use]
+    pub fn time(self) -> Option<Time<'a>> {
+        Some(Time {
+            spec: self.spec.time.as_ref()?,
+            ast: self,
+        })
+    }
+
+    /// The timezone to convert the result into, given with a trailing "in" or "to"
+    #[must_use]
+    pub fn convert(self) -> Option<Zone<'a>> {
+        Some(Zone {
+            zone: self.spec.convert.as_ref()?,
+            span: self.spec.spans.convert.clone(),
+        })
+    }
+}
+
+/// A date, relative to the base time or not
+#[derive(Clone, Copy, Debug)]
+pub struct Date<'a> {
+    spec: &'a DateSpec,
+    ast: Ast<'a>,
+}
+
+impl Date<'_> {
+    /// What kind of date this is
+    #[must_use]
+    pub fn kind(self) -> DateKind {
+        match self.spec {
+            DateSpec::Absolute(date) => DateKind::Absolute {
+                year: date.year,
+                month: date.month,
+                day: date.day,
+            },
+            DateSpec::Relative(interval) => DateKind::Relative(interval.clone()),
+            DateSpec::FromName(name, direction) => DateKind::Named {
+                name: match *name {
+                    ByName::WeekDay(day) => Name::Weekday(day),
+                    ByName::MonthName(month) => Name::Month(month),
+                    ByName::DayMonth { day, month } => Name::DayMonth { day, month },
+                },
+                direction: match direction {
+                    crate::types::Direction::Next => Direction::Next,
+                    crate::types::Direction::Last => Direction::Last,
+                    crate::types::Direction::Here => Direction::This,
+                },
+            },
+            DateSpec::Timestamp(timestamp) => DateKind::Timestamp {
+                seconds: timestamp.secs,
+                microseconds: timestamp.micros,
+            },
+        }
+    }
+
+    /// Whether the date depends on the base time, like "tomorrow" or "friday"
+    #[must_use]
+    pub fn is_relative(self) -> bool {
+        matches!(self.spec, DateSpec::Relative(_) | DateSpec::FromName(..))
+    }
+
+    /// Where the date was found in the text
+    #[must_use]
+    pub fn span(self) -> Span {
+        self.ast.spec.spans.date.clone()
+    }
+}
+
+/// The kinds of date
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateKind {
+    /// A calendar date, like "2018-06-02" or "4 July 2017".
+    /// It isn't checked to exist until it's evaluated.
+    Absolute { year: i32, month: u32, day: u32 },
+    /// An offset from the base time, like "3 days ago" or "tomorrow"
+    Relative(Interval),
+    /// A day or month by name, like "next friday", "last july" or "8/11"
+    Named { name: Name, direction: Direction },
+    /// A unix timestamp, like "@1700000000.5"
+    Timestamp { seconds: i64, microseconds: u32 },
+}
+
+/// A day or month given by name
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Name {
+    /// A day of the week, counting from 0 for Monday
+    Weekday(u8),
+    /// A month, from 1 for January
+    Month(u32),
+    /// A day of a month, like "4 July"
+    DayMonth { day: u32, month: u32 },
+}
+
+/// Which occurrence of a named day or month
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Direction {
+    /// "next friday"
+    Next,
+    /// "last friday"
+    Last,
+    /// "this friday", or just "friday"
+    This,
+}
+
+/// A time of day
+#[derive(Clone, Copy, Debug)]
+pub struct Time<'a> {
+    spec: &'a TimeSpec,
+    ast: Ast<'a>,
+}
+
+impl<'a> Time<'a> {
+    /// The hour, on the 24 hour clock
+    #[must_use]
+    pub fn hour(self) -> u32 {
+        self.spec.hour
+    }
+
+    /// The minute, from 0 to 59 in a valid time
+    #[must_use]
+    pub fn minute(self) -> u32 {
+        self.spec.min
+    }
+
+    /// The second, from 0 to 59 in a valid time
+    #[must_use]
+    pub fn second(self) -> u32 {
+        self.spec.sec
+    }
+
+    /// The microsecond, from 0 to 999999
+    #[must_use]
+    pub fn microsecond(self) -> u32 {
+        self.spec.microsec
+    }
+
+    /// The timezone the time was given in, like "9am PST" or "9am in Tokyo"
+    #[must_use]
+    pub fn zone(self) -> Option<Zone<'a>> {
+        Some(Zone {
+            zone: self.spec.zone.as_ref()?,
+            span: self.ast.spec.spans.zone.clone(),
+        })
+    }
+
+    /// Where the time was found in the text, not including its timezone
+    #[must_use]
+    pub fn span(self) -> Span {
+        self.ast.spec.spans.time.clone()
+    }
+}
+
+/// A timezone or offset
+#[derive(Clone, Debug)]
+pub struct Zone<'a> {
+    zone: &'a crate::tz::Zone,
+    span: Span,
+}
+
+impl<'a> Zone<'a> {
+    /// The offset east of UTC in seconds, for offsets and abbreviations like "+05:30" or "PST"
+    #[must_use]
+    pub fn fixed_offset(&self) -> Option<i64> {
+        match self.zone {
+            crate::tz::Zone::Fixed(offset) => Some(*offset),
+            #[cfg(feature = "jiff_0_2")]
+            crate::tz::Zone::Jiff(_) => None,
+            #[cfg(feature = "chrono_tz_0_10")]
+            crate::tz::Zone::ChronoTz(_) => None,
+        }
+    }
+
+    /// The IANA name, for named zones like "Europe/London" or "Tokyo"
+    #[must_use]
+    pub fn iana_name(&self) -> Option<&'a str> {
+        self.zone.iana_name()
+    }
+
+    /// Where the zone was found in the text
+    #[must_use]
+    pub fn span(&self) -> Span {
+        self.span.clone()
+    }
+}
@@ -0,0 +1,174 @@
+use core::fmt::{self, Write};
+
+use crate::humanize::{MONTHS, WEEKDAYS};
+use crate::parser::DateParser;
+use crate::types::{ByName, DateSpec, DateTimeSpec, Direction, Timestamp};
+use crate::tz::Zone;
+use crate::{DateError, DateResult, Dialect, Interval, Options};
+
+/// The canonical form of a date-time expression, from [`canonicalize`].
+/// `Display` writes it, like "next friday 20:00".
+#[derive(Clone, Debug)]
+pub struct Canonical {
+    spec: DateTimeSpec,
+}
+
+/// Write the text in a canonical form, so that the different ways of writing the same
+/// expression give the same text, like "next friday 20:00" for "next fri 8pm" and
+/// "Next Friday 20.00". See [`canonicalize_with`].
+///
+/// ```
+/// use interim::{canonicalize, Dialect};
+///
+/// let canonical = |text| canonicalize(text, Dialect::Uk).unwrap().to_string();
+/// assert_eq!(canonical("next fri 8 PM"), "next friday 20:00");
+/// assert_eq!(canonical("Next Friday 20.00"), "next friday 20:00");
+/// assert_eq!(canonical("4 July 2018 at noon EST"), "2018-07-04 12:00 -05:00");
+/// assert_eq!(canonical("in 90 minutes"), "1 hour 30 minutes");
+/// assert_eq!(canonical("8/11 9am"), "november 8 09:00");
+/// assert_eq!(canonicalize("8/11 9am", Dialect::Us).unwrap().to_string(), "august 11 09:00");
+/// ```
+pub fn canonicalize(s: &str, dialect: Dialect) -> DateResult<Canonical> {
+    canonicalize_with(s, Options::new(dialect))
+}
+
+/// Write the text in a canonical form, with some extra configuration [`Options`].
+///
+/// The canonical form describes what the text says without evaluating it against a base time,
+/// and it doesn't depend on the options: dates are written like "2018-07-04" or "july 4",
+/// times on the 24 hour clock, durations in their largest units, timestamps after an '@',
+/// timezones as offsets or IANA names, and conversions with "to". Parsing it with any
+/// [`Dialect`] gives the same expression as the original text, so it evaluates to the same
+/// date-time with the original options.
+///
+/// # Errors
+///
+/// Any error from parsing the text, [`DateError::MissingDate`] for a month that doesn't exist,
+/// like "1/13" in the UK, or [`DateError::UnsupportedTimeZone`] for a timezone without a name.
+pub fn canonicalize_with(s: &str, options: Options) -> DateResult<Canonical> {
+    let spec = DateParser::new(s).parse(options)?;
+    // months are written by name
+    if let Some(DateSpec::FromName(ByName::DayMonth { month, .. }, _)) = spec.date {
+        if !(1..=12).contains(&month) {
+            return Err(DateError::MissingDate);
+        }
+    }
+    // named zones are written by their IANA name
+    #[cfg(any(feature = "jiff_0_2", feature = "chrono_tz_0_10"))]
+    {
+        let zones = [
+            spec.time.as_ref().and_then(|time| time.zone.as_ref()),
+            spec.convert.as_ref(),
+        ];
+        let unnamed = |zone: &Zone| !matches!(zone, Zone::Fixed(_)) && zone.iana_name().is_none();
+        if zones.into_iter().flatten().any(unnamed) {
+            return Err(DateError::UnsupportedTimeZone);
+        }
+    }
+    Ok(Canonical { spec })
+}
+
+impl fmt::Display for Canonical {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let DateTimeSpec {
+            date,
+            time,
+            convert,
+            ..
+        } = &self.spec;
+
+        match date {
+            None => {}
+            Some(DateSpec::Absolute(date)) => {
+                write!(f, "{:04}-{:02}-{:02}", date.year, date.month, date.day)?;
+            }
+            Some(DateSpec::Relative(interval)) => match *interval {
+                // "today" on its own keeps the time of day
+                Interval::Days(0) if time.is_none() => f.write_str("now")?,
+                Interval::Days(0) => f.write_str("today")?,
+                Interval::Days(-1) => f.write_str("yesterday")?,
+                Interval::Days(1) => f.write_str("tomorrow")?,
+                _ => write!(f, "{interval}")?,
+            },
+            Some(DateSpec::FromName(name, direct)) => {
+                match direct {
+                    Direction::Next => f.write_str("next ")?,
+                    Direction::Last => f.write_str("last ")?,
+                    Direction::Here => {}
+                }
+                match *name {
+                    ByName::WeekDay(weekday) => lowercase(f, WEEKDAYS[usize::from(weekday)])?,
+                    ByName::MonthName(month) => lowercase(f, MONTHS[month as usize - 1])?,
+                    // the day first could be read as a year, like "4 july 20"
+                    ByName::DayMonth { day, month } => {
+                        lowercase(f, MONTHS[month as usize - 1])?;
+                        write!(f, " {day}")?;
+                    }
+                }
+            }
+            Some(DateSpec::Timestamp(Timestamp { secs, micros })) => {
+                let micros = i128::from(*secs) * 1_000_000 + i128::from(*micros);
+                let sign = if micros < 0 { "-" } else { "" };
+                let micros = micros.unsigned_abs();
+                write!(f, "@{sign}{}", micros / 1_000_000)?;
+                fraction(f, (micros % 1_000_000) as u32)?;
+            }
+        }
+
+        if let Some(time) = time {
+            match date {
+                None => {}
+                // a number after a month name is its day
+                Some(DateSpec::FromName(ByName::MonthName(_), _)) => f.write_str(" at ")?,
+                Some(_) => f.write_str(" ")?,
+            }
+            write!(f, "{:02}:{:02}", time.hour, time.min)?;
+            if time.sec != 0 || time.microsec != 0 {
+                write!(f, ":{:02}", time.sec)?;
+                fraction(f, time.microsec)?;
+            }
+            if let Some(zone) = &time.zone {
+                f.write_str(" ")?;
+                write_zone(f, zone)?;
+            }
+        }
+
+        if let Some(zone) = convert {
+            // "in" after a time would be the time's own timezone
+            f.write_str(" to ")?;
+            write_zone(f, zone)?;
+        }
+        Ok(())
+    }
+}
+
+fn lowercase(f: &mut fmt::Formatter<'_>, name: &str) -> fmt::Result {
+    name.chars()
+        .try_for_each(|c| f.write_char(c.to_ascii_lowercase()))
+}
+
+// the digits after the decimal point, without trailing zeros
+fn fraction(f: &mut fmt::Formatter<'_>, mut micros: u32) -> fmt::Result {
+    if micros == 0 {
+        return Ok(());
+    }
+    let mut width = 6;
+    while micros % 10 == 0 {
+        micros /= 10;
+        width -= 1;
+    }
+    write!(f, ".{micros:0width$}")
+}
+
+fn write_zone(f: &mut fmt::Formatter<'_>, zone: &Zone) -> fmt::Result {
+    match zone {
+        Zone::Fixed(offset) => {
+            let sign = if *offset < 0 { '-' } else { '+' };
+            let minutes = offset.unsigned_abs() / 60;
+            write!(f, "{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+        }
+        // zones without a name are rejected by `canonicalize_with`
+        #[cfg(any(feature = "jiff_0_2", feature = "chrono_tz_0_10"))]
+        _ => f.write_str(zone.iana_name().unwrap_or_default()),
+    }
+}
@@ -0,0 +1,276 @@
+use core::fmt;
+
+/// A date in the proleptic Gregorian calendar
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// The date with the given year, month (1-12) and day of the month (1-31),
+    /// if it exists
+    #[must_use]
+    pub const fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if month == 0 || month > 12 || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    #[must_use]
+    pub const fn year(self) -> i32 {
+        self.year
+    }
+
+    #[must_use]
+    pub const fn month(self) -> u8 {
+        self.month
+    }
+
+    #[must_use]
+    pub const fn day(self) -> u8 {
+        self.day
+    }
+
+    /// The day of the week, counting from 0 for Monday
+    #[must_use]
+    pub const fn weekday(self) -> u8 {
+        // 1970-01-01 was a Thursday
+        (self.to_days() + 3).rem_euclid(7) as u8
+    }
+
+    /// Days since 1970-01-01
+    pub(crate) const fn to_days(self) -> i64 {
+        // http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let y = self.year as i64 - (self.month <= 2) as i64;
+        let m = self.month as i64;
+        let era = y.div_euclid(400);
+        let yoe = y - era * 400;
+        let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + self.day as i64 - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// The date a number of days after 1970-01-01
+    pub(crate) const fn from_days(days: i64) -> Option<Self> {
+        // http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let Some(z) = days.checked_add(719_468) else {
+            return None;
+        };
+        let era = z.div_euclid(146_097);
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = yoe + era * 400 + (month <= 2) as i64;
+        if year < i32::MIN as i64 || year > i32::MAX as i64 {
+            return None;
+        }
+        Some(Self {
+            year: year as i32,
+            month,
+            day,
+        })
+    }
+}
+
+const fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub(crate) const fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A time of day, with microsecond precision
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+    second: u8,
+    microsecond: u32,
+}
+
+impl Time {
+    pub const MIDNIGHT: Self = Self {
+        hour: 0,
+        minute: 0,
+        second: 0,
+        microsecond: 0,
+    };
+
+    /// The time with the given hour (0-23), minute (0-59) and second (0-59), if it is valid
+    #[must_use]
+    pub const fn new(hour: u8, minute: u8, second: u8) -> Option<Self> {
+        if hour > 23 || minute > 59 || second > 59 {
+            return None;
+        }
+        Some(Self {
+            hour,
+            minute,
+            second,
+            microsecond: 0,
+        })
+    }
+
+    /// The same time with the given microsecond (0-999999), if it is valid
+    #[must_use]
+    pub const fn with_microsecond(mut self, microsecond: u32) -> Option<Self> {
+        if microsecond >= 1_000_000 {
+            return None;
+        }
+        self.microsecond = microsecond;
+        Some(self)
+    }
+
+    #[must_use]
+    pub const fn hour(self) -> u8 {
+        self.hour
+    }
+
+    #[must_use]
+    pub const fn minute(self) -> u8 {
+        self.minute
+    }
+
+    #[must_use]
+    pub const fn second(self) -> u8 {
+        self.second
+    }
+
+    #[must_use]
+    pub const fn microsecond(self) -> u32 {
+        self.microsecond
+    }
+
+    /// Seconds since midnight
+    const fn to_seconds(self) -> i64 {
+        self.hour as i64 * 3600 + self.minute as i64 * 60 + self.second as i64
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)?;
+        if self.microsecond != 0 {
+            write!(f, ".{:06}", self.microsecond)?;
+        }
+        Ok(())
+    }
+}
+
+/// A date and time at a fixed offset from UTC.
+///
+/// This needs no other date-time crates, but has no timezone rules,
+/// so "tomorrow 9am" keeps the offset of the base time.
+///
+/// ```
+/// use interim::{civil, parse_date_string, Dialect};
+///
+/// let date = civil::Date::new(2022, 9, 17).unwrap();
+/// let time = civil::Time::new(13, 27, 0).unwrap();
+/// let now = civil::DateTime::from_parts(date, time, 3600).unwrap();
+///
+/// let this_friday = parse_date_string("friday 8pm", now, Dialect::Uk).unwrap();
+/// assert_eq!(this_friday.to_string(), "2022-09-23T20:00:00+01:00");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    date: Date,
+    time: Time,
+    offset: i32,
+}
+
+impl DateTime {
+    /// The date and time at `offset` seconds east of UTC.
+    /// The offset must be less than a day.
+    #[must_use]
+    pub const fn from_parts(date: Date, time: Time, offset: i32) -> Option<Self> {
+        if offset <= -86400 || offset >= 86400 {
+            return None;
+        }
+        Some(Self { date, time, offset })
+    }
+
+    /// The instant given in seconds and microseconds since the unix epoch,
+    /// at `offset` seconds east of UTC
+    #[must_use]
+    pub const fn from_unix(secs: i64, microsecond: u32, offset: i32) -> Option<Self> {
+        if offset <= -86400 || offset >= 86400 || microsecond >= 1_000_000 {
+            return None;
+        }
+        let Some(local) = secs.checked_add(offset as i64) else {
+            return None;
+        };
+        let Some(date) = Date::from_days(local.div_euclid(86400)) else {
+            return None;
+        };
+        let secs = local.rem_euclid(86400);
+        let time = Time {
+            hour: (secs / 3600) as u8,
+            minute: (secs / 60 % 60) as u8,
+            second: (secs % 60) as u8,
+            microsecond,
+        };
+        Some(Self { date, time, offset })
+    }
+
+    #[must_use]
+    pub const fn date(self) -> Date {
+        self.date
+    }
+
+    #[must_use]
+    pub const fn time(self) -> Time {
+        self.time
+    }
+
+    /// The offset from UTC, in seconds east
+    #[must_use]
+    pub const fn offset(self) -> i32 {
+        self.offset
+    }
+
+    /// Seconds since the unix epoch, ignoring the microseconds
+    #[must_use]
+    pub const fn unix_timestamp(self) -> i64 {
+        self.date.to_days() * 86400 + self.time.to_seconds() - self.offset as i64
+    }
+
+    /// The same instant at another offset
+    #[must_use]
+    pub const fn to_offset(self, offset: i32) -> Option<Self> {
+        Self::from_unix(self.unix_timestamp(), self.time.microsecond, offset)
+    }
+}
+
+/// RFC 3339 format, like `2018-03-21T11:00:00+02:00`
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.offset < 0 { '-' } else { '+' };
+        let offset = self.offset.unsigned_abs();
+        write!(
+            f,
+            "{}T{}{sign}{:02}:{:02}",
+            self.date,
+            self.time,
+            offset / 3600,
+            offset / 60 % 60
+        )
+    }
+}
@@ -0,0 +1,315 @@
+//! Value parsers for reading dates and durations from command-line arguments with clap.
+//!
+//! ```
+//! use clap::{Arg, Command};
+//! use interim::{Dialect, Interval};
+//! use std::time::SystemTime;
+//!
+//! let command = Command::new("backup")
+//!     .arg(
+//!         Arg::new("since")
+//!             .long("since")
+//!             .value_parser(interim::clap::datetime::<SystemTime>(Dialect::Uk))
+//!             .long_help(interim::clap::DATETIME_HELP),
+//!     )
+//!     .arg(
+//!         Arg::new("every")
+//!             .long("every")
+//!             .value_parser(interim::clap::duration())
+//!             .long_help(interim::clap::DURATION_HELP),
+//!     );
+//!
+//! let matches = command
+//!     .try_get_matches_from(["backup", "--since", "last friday 9am", "--every", "2h"])
+//!     .unwrap();
+//! let since: &SystemTime = matches.get_one("since").unwrap();
+//! assert_eq!(matches.get_one::<Interval>("every"), Some(&Interval::Seconds(7200)));
+//! ```
+//!
+//! Relative dates are evaluated against the time the first of these parsers was made, which
+//! for clap is while building the command, so every argument is relative to the same time.
+//! Errors point at the part of the argument that couldn't be understood:
+//!
+//! ```text
+//! error: invalid value '3 blorps' for '--since <since>': expected month or time unit
+//!
+//!   3 blorps
+//!     ^^^^^^
+//! ```
+
+use std::ffi::OsStr;
+use std::fmt::Write;
+use std::format;
+use std::string::{String, ToString};
+use std::sync::OnceLock;
+use std::time::SystemTime;
+
+use ::clap::builder::TypedValueParser;
+use ::clap::error::{Error, ErrorKind};
+use ::clap::{Arg, Command};
+
+use crate::datetime::DateTime;
+use crate::{parse_date_string_with, parse_duration, DateError, Interval, Options};
+
+/// Help text listing the forms [`duration`] accepts, for [`Arg::long_help`]
+pub const DURATION_HELP: &str = "\
+A number of seconds, minutes, hours, days, weeks, months or years, like:
+  15m, 90 seconds, 2 hours, 3 days, 2 weeks, 1 year
+Add \"ago\" for a negative duration, like \"3 days ago\".";
+
+/// Help text listing the forms [`datetime`] accepts, for [`Arg::long_help`]
+pub const DATETIME_HELP: &str = "\
+A date, a time of day, or both, like:
+  2018-04-01, 1 April 2018, 04/01/18
+  today, tomorrow, friday, next friday, last july, april 1
+  3 days ago, 2 weeks, 1 month
+  9am, 18:03, 6.03pm, noon, midnight
+  next friday 8pm, 2018-04-01 18:03:40 +05:30
+A time may be followed by a timezone, like \"9am PST\", and the result can be
+converted into another with a trailing \"in\", like \"now in UTC\".";
+
+/// Parse durations like "15m" or "2 weeks" into an [`Interval`]
+#[must_use]
+pub fn duration() -> DurationParser {
+    DurationParser
+}
+
+/// Parse dates and times like "next friday 8pm", relative to the start time
+#[must_use]
+pub fn datetime<Dt: StartTime>(options: impl Into<Options>) -> DateTimeParser<Dt> {
+    DateTimeParser {
+        options: options.into(),
+        start: Dt::from_system_time(start_time()),
+    }
+}
+
+/// The value parser from [`duration`]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DurationParser;
+
+impl TypedValueParser for DurationParser {
+    type Value = Interval;
+
+    fn parse_ref(
+        &self,
+        cmd: &Command,
+        arg: Option<&Arg>,
+        value: &OsStr,
+    ) -> Result<Interval, Error> {
+        let text = to_str(cmd, value)?;
+        parse_duration(text).map_err(|err| invalid(cmd, arg, text, &err))
+    }
+}
+
+/// The value parser from [`datetime`]
+#[derive(Clone, Debug)]
+pub struct DateTimeParser<Dt> {
+    options: Options,
+    start: Dt,
+}
+
+impl<Dt: StartTime> TypedValueParser for DateTimeParser<Dt> {
+    type Value = Dt;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<Dt, Error> {
+        let text = to_str(cmd, value)?;
+        parse_date_string_with(text, self.start.clone(), self.options)
+            .map_err(|err| invalid(cmd, arg, text, &err))
+    }
+}
+
+/// A date-time type that [`datetime`] can give, made from the start time
+pub trait StartTime: DateTime + Clone + Send + Sync + 'static {
+    /// The date-time at the instant, in the timezone the results should be given in
+    fn from_system_time(time: SystemTime) -> Self;
+}
+
+/// An instant, with calendar operations in UTC
+impl StartTime for SystemTime {
+    fn from_system_time(time: SystemTime) -> Self {
+        time
+    }
+}
+
+#[cfg(feature = "chrono_0_4")]
+mod chrono {
+    use super::{unix_nanos, StartTime, SystemTime};
+    use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
+
+    /// The start time in UTC
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono_0_4")))]
+    impl StartTime for DateTime<Utc> {
+        fn from_system_time(time: SystemTime) -> Self {
+            let nanos = unix_nanos(time);
+            Utc.timestamp_opt(
+                nanos.div_euclid(1_000_000_000) as i64,
+                nanos.rem_euclid(1_000_000_000) as u32,
+            )
+            .single()
+            .expect("the system time should be in range for chrono")
+        }
+    }
+
+    /// The start time in the system's local timezone
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono_0_4")))]
+    impl StartTime for DateTime<Local> {
+        fn from_system_time(time: SystemTime) -> Self {
+            DateTime::<Utc>::from_system_time(time).with_timezone(&Local)
+        }
+    }
+
+    /// The start time on the local wall clock
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono_0_4")))]
+    impl StartTime for NaiveDateTime {
+        fn from_system_time(time: SystemTime) -> Self {
+            DateTime::<Local>::from_system_time(time).naive_local()
+        }
+    }
+}
+
+#[cfg(feature = "time_0_3")]
+mod time {
+    use super::{unix_nanos, StartTime, SystemTime};
+    use time::{OffsetDateTime, UtcDateTime};
+
+    /// The start time in UTC
+    #[cfg_attr(docsrs, doc(cfg(feature = "time_0_3")))]
+    impl StartTime for OffsetDateTime {
+        fn from_system_time(time: SystemTime) -> Self {
+            OffsetDateTime::from_unix_timestamp_nanos(unix_nanos(time))
+                .expect("the system time should be in range for time")
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "time_0_3")))]
+    impl StartTime for UtcDateTime {
+        fn from_system_time(time: SystemTime) -> Self {
+            UtcDateTime::from_unix_timestamp_nanos(unix_nanos(time))
+                .expect("the system time should be in range for time")
+        }
+    }
+}
+
+#[cfg(feature = "jiff_0_1")]
+mod jiff_0_1 {
+    use super::{unix_nanos, StartTime, SystemTime};
+    use jiff_0_1::{civil::DateTime, tz::TimeZone, Timestamp, Zoned};
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_1")))]
+    impl StartTime for Timestamp {
+        fn from_system_time(time: SystemTime) -> Self {
+            Timestamp::from_nanosecond(unix_nanos(time))
+                .expect("the system time should be in range for jiff")
+        }
+    }
+
+    /// The start time in the system's timezone
+    #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_1")))]
+    impl StartTime for Zoned {
+        fn from_system_time(time: SystemTime) -> Self {
+            Timestamp::from_system_time(time).to_zoned(TimeZone::system())
+        }
+    }
+
+    /// The start time on the wall clock of the system's timezone
+    #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_1")))]
+    impl StartTime for DateTime {
+        fn from_system_time(time: SystemTime) -> Self {
+            Zoned::from_system_time(time).datetime()
+        }
+    }
+}
+
+#[cfg(feature = "jiff_0_2")]
+mod jiff_0_2 {
+    use super::{unix_nanos, StartTime, SystemTime};
+    use jiff_0_2::{civil::DateTime, tz::TimeZone, Timestamp, Zoned};
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_2")))]
+    impl StartTime for Timestamp {
+        fn from_system_time(time: SystemTime) -> Self {
+            Timestamp::from_nanosecond(unix_nanos(time))
+                .expect("the system time should be in range for jiff")
+        }
+    }
+
+    /// The start time in the system's timezone
+    #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_2")))]
+    impl StartTime for Zoned {
+        fn from_system_time(time: SystemTime) -> Self {
+            Timestamp::from_system_time(time).to_zoned(TimeZone::system())
+        }
+    }
+
+    /// The start time on the wall clock of the system's timezone
+    #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_2")))]
+    impl StartTime for DateTime {
+        fn from_system_time(time: SystemTime) -> Self {
+            Zoned::from_system_time(time).datetime()
+        }
+    }
+}
+
+// the time the first parser was made
+fn start_time() -> SystemTime {
+    static START: OnceLock<SystemTime> = OnceLock::new();
+    *START.get_or_init(SystemTime::now)
+}
+
+#[cfg(any(
+    feature = "chrono_0_4",
+    feature = "time_0_3",
+    feature = "jiff_0_1",
+    feature = "jiff_0_2"
+))]
+fn unix_nanos(time: SystemTime) -> i128 {
+    match time.duration_since(std::time::UNIX_EPOCH) {
+        Ok(after) => after.as_nanos() as i128,
+        Err(before) => -(before.duration().as_nanos() as i128),
+    }
+}
+
+fn to_str<'a>(cmd: &Command, value: &'a OsStr) -> Result<&'a str, Error> {
+    value.to_str().ok_or_else(|| {
+        Error::raw(
+            ErrorKind::InvalidUtf8,
+            "invalid UTF-8 was detected in one or more arguments\n",
+        )
+        .with_cmd(cmd)
+    })
+}
+
+// the error, with the part of the text it's about underlined
+fn invalid(cmd: &Command, arg: Option<&Arg>, text: &str, err: &DateError) -> Error {
+    let arg = arg.map_or_else(|| "...".into(), ToString::to_string);
+    let mut message = format!("invalid value '{text}' for '{arg}': {}\n", strip_span(err));
+
+    let span = match err {
+        DateError::ExpectedToken(_, span) | DateError::Overflow(span) => Some(span.clone()),
+        DateError::EndOfText(_) => Some(text.len()..text.len()),
+        _ => None,
+    };
+    if let Some(span) = span {
+        let start = text.get(..span.start).map_or(0, |s| s.chars().count());
+        let width = text.get(span).map_or(0, |s| s.chars().count()).max(1);
+        let _ = write!(
+            message,
+            "\n  {text}\n  {:start$}{:^<width$}\n",
+            "",
+            "",
+            start = start,
+            width = width,
+        );
+    }
+
+    Error::raw(ErrorKind::ValueValidation, message).with_cmd(cmd)
+}
+
+// the span is shown underneath, so it's left out of the message
+fn strip_span(err: &DateError) -> String {
+    match err {
+        DateError::ExpectedToken(expected, _) => format!("expected {expected}"),
+        DateError::Overflow(_) => "the quantity is too large".into(),
+        err => err.to_string(),
+    }
+}
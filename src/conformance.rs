@@ -0,0 +1,504 @@
+//! A test suite for [`DateTime`] implementations.
+//!
+//! This is the suite interim runs against its own date-time types. It's useful to check
+//! types that implement [`v1::DateTime`](crate::datetime::v1::DateTime).
+//!
+//! Every check uses the same base time: 2018-03-21 at 11:00, a Wednesday.
+//! The suite panics with a description of the first input that gives a different result.
+//! The checks with IANA timezone names, like `Harness::named_zones`, are separate methods,
+//! as they need a timezone database.
+//!
+//! ```
+//! use interim::conformance::Harness;
+//! use interim::civil;
+//!
+//! let date = civil::Date::new(2018, 3, 21).unwrap();
+//! let time = civil::Time::new(11, 0, 0).unwrap();
+//! let base = civil::DateTime::from_parts(date, time, 7200).unwrap();
+//!
+//! let harness = Harness::zoned(base, |date| date.to_string());
+//! harness.acceptance();
+//! harness.conversion();
+//! ```
+
+// every check panics on failure, as described above
+#![allow(clippy::missing_panics_doc)]
+
+use std::borrow::ToOwned;
+use std::fmt::Debug;
+use std::format;
+use std::string::{String, ToString};
+
+use crate::civil;
+use crate::datetime::DateTime;
+use crate::{
+    parse_date_string, parse_date_string_with, DateError, Dialect, Disambiguation, Options,
+};
+
+/// How a date-time type relates to a timezone
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Kind {
+    /// A date-time in a timezone or at an offset, like `chrono::DateTime<FixedOffset>`
+    Zoned,
+    /// A date-time without a timezone, like `chrono::NaiveDateTime`
+    Naive,
+    /// An instant, with dates and times in UTC, like `jiff::Timestamp`
+    Utc,
+}
+
+/// Runs the test suites for a date-time type
+#[derive(Clone, Debug)]
+pub struct Harness<Dt> {
+    base: Dt,
+    format: fn(&Dt) -> String,
+    kind: Kind,
+}
+
+impl<Dt: DateTime + Clone + Debug> Harness<Dt> {
+    /// A harness for a type with a timezone or offset.
+    ///
+    /// `base` must be 2018-03-21T11:00:00+02:00,
+    /// and `format` must give RFC 3339 with a numeric offset, like "2018-03-21T11:00:00+02:00".
+    /// Fractional seconds must be given when there are any, with any number of digits.
+    pub fn zoned(base: Dt, format: fn(&Dt) -> String) -> Self {
+        Self::new(base, format, Kind::Zoned)
+    }
+
+    /// A harness for a type without a timezone.
+    ///
+    /// `base` must be 2018-03-21T11:00:00, and `format` must give RFC 3339 without an offset,
+    /// like "2018-03-21T11:00:00", and fractional seconds like [`Harness::zoned`].
+    pub fn naive(base: Dt, format: fn(&Dt) -> String) -> Self {
+        Self::new(base, format, Kind::Naive)
+    }
+
+    /// A harness for an instant, with dates and times in UTC.
+    ///
+    /// `base` must be 2018-03-21T11:00:00Z, and `format` must give RFC 3339 in UTC,
+    /// like "2018-03-21T11:00:00+00:00", and fractional seconds like [`Harness::zoned`].
+    pub fn utc(base: Dt, format: fn(&Dt) -> String) -> Self {
+        Self::new(base, format, Kind::Utc)
+    }
+
+    /// A harness for a type of the given [`Kind`]
+    pub fn new(base: Dt, format: fn(&Dt) -> String, kind: Kind) -> Self {
+        Self { base, format, kind }
+    }
+
+    /// The expected output given the `expected` output for a zoned base time in +02:00.
+    ///
+    /// Inputs that describe an `instant` are the same instant for every base time,
+    /// and give `None` if naive types can't represent them.
+    fn expected(&self, expected: &str, instant: bool) -> Option<String> {
+        let (local, offset) = expected.split_at(expected.len() - 6);
+        assert_eq!(offset, "+02:00");
+        let (date_time, fraction) = local.split_at(19);
+        match self.kind {
+            Kind::Zoned => Some(expected.to_owned()),
+            Kind::Naive => (!instant).then(|| local.to_owned()),
+            Kind::Utc if instant => Some(format!(
+                "{}{fraction}+00:00",
+                &to_utc(date_time, 2 * 3600)[..19]
+            )),
+            Kind::Utc => Some(format!("{local}+00:00")),
+        }
+    }
+
+    fn check(&self, input: &str, options: Options, instant: bool, expected: &str) {
+        let Some(expected) = self.expected(expected, instant) else {
+            let err = parse_date_string_with(input, self.base.clone(), options);
+            assert_eq!(
+                err.err(),
+                Some(DateError::UnsupportedTimeZone),
+                "expected an error attempting to parse {input:?}"
+            );
+            return;
+        };
+        self.exact(input, options, &expected);
+    }
+
+    fn exact(&self, input: &str, options: Options, expected: &str) {
+        let date = match parse_date_string_with(input, self.base.clone(), options) {
+            Ok(date) => date,
+            Err(e) => {
+                panic!("unexpected error attempting to parse {input:?} with {options:?}\n\t{e:?}")
+            }
+        };
+
+        let actual = trim_fraction(&(self.format)(&date));
+        assert_eq!(actual, expected, "unexpected output attempting to format {input:?} with {options:?}.\nexpected: {expected:?}\n  parsed: {date:?} [{actual:?}]");
+    }
+
+    fn assert(&self, input: &str, dialect: Dialect, expected: &str) {
+        self.check(input, Options::new(dialect), false, expected);
+    }
+
+    // inputs that describe an instant can't give a date-time without a timezone
+    fn instant(&self, input: &str, dialect: Dialect, expected: &str) {
+        self.check(input, Options::new(dialect), true, expected);
+    }
+
+    /// The main test suite: relative and absolute dates, times, timezones and timestamps
+    pub fn acceptance(&self) {
+        use Dialect::{Uk, Us};
+
+        // Day of week - relative to today. May have a time part
+        self.assert("friday", Uk, "2018-03-23T00:00:00+02:00");
+        self.assert("friday 10:30", Uk, "2018-03-23T10:30:00+02:00");
+        self.assert("friday 8pm", Uk, "2018-03-23T20:00:00+02:00");
+        self.assert("12am", Uk, "2018-03-21T00:00:00+02:00");
+        self.assert("12pm", Uk, "2018-03-21T12:00:00+02:00");
+        self.assert("7:26 AM", Uk, "2018-03-21T07:26:00+02:00");
+        self.assert("7:26 PM", Uk, "2018-03-21T19:26:00+02:00");
+
+        // The day of week is the _next_ day after today, so "Tuesday" is the next Tuesday after Wednesday
+        self.assert("tues", Uk, "2018-03-27T00:00:00+02:00");
+
+        // The expression 'next Monday' is ambiguous; in the US it means the day following (same as 'Monday')
+        // (This is how the `date` command interprets it)
+        self.assert("next mon", Us, "2018-03-26T00:00:00+02:00");
+        // but otherwise it means the day in the next week..
+        self.assert("next mon", Uk, "2018-04-02T00:00:00+02:00");
+
+        self.assert("last year", Uk, "2017-03-21T00:00:00+02:00");
+        self.assert("this year", Uk, "2018-03-21T00:00:00+02:00");
+        self.assert("next year", Uk, "2019-03-21T00:00:00+02:00");
+
+        self.assert("last fri 9.30", Uk, "2018-03-16T09:30:00+02:00");
+
+        // date expressed as month, day - relative to today. May have a time part
+        self.assert("8/11", Us, "2018-08-11T00:00:00+02:00");
+        self.assert("last 8/11", Us, "2017-08-11T00:00:00+02:00");
+        self.assert("last 8/11 9am", Us, "2017-08-11T09:00:00+02:00");
+        self.assert("8/11", Uk, "2018-11-08T00:00:00+02:00");
+        self.assert("last 8/11", Uk, "2017-11-08T00:00:00+02:00");
+        self.assert("last 8/11 9am", Uk, "2017-11-08T09:00:00+02:00");
+        self.assert("April 1 8.30pm", Uk, "2018-04-01T20:30:00+02:00");
+
+        // advance by time unit from today
+        // without explicit time, use base time - otherwise override
+        self.assert("2d", Uk, "2018-03-23T11:00:00+02:00");
+        self.assert("2d 03:00", Uk, "2018-03-23T03:00:00+02:00");
+        self.assert("3 weeks", Uk, "2018-04-11T11:00:00+02:00");
+        self.assert("3h", Uk, "2018-03-21T14:00:00+02:00");
+        self.assert("6 months", Uk, "2018-09-21T00:00:00+02:00");
+        self.assert("6 months ago", Uk, "2017-09-21T00:00:00+02:00");
+        self.assert("3 hours ago", Uk, "2018-03-21T08:00:00+02:00");
+        self.assert(" -3h", Uk, "2018-03-21T08:00:00+02:00");
+        self.assert(" -3 month", Uk, "2017-12-21T00:00:00+02:00");
+
+        // absolute date with year, month, day - formal ISO and informal UK or US
+        self.assert("2017-06-30", Uk, "2017-06-30T00:00:00+02:00");
+        self.assert("30/06/17", Uk, "2017-06-30T00:00:00+02:00");
+        self.assert("06/30/17", Us, "2017-06-30T00:00:00+02:00");
+
+        // may be followed by time part, formal and informal
+        self.assert("2017-06-30 08:20:30", Uk, "2017-06-30T08:20:30+02:00");
+        self.instant(
+            "2017-06-30 08:20:30 +04:00",
+            Uk,
+            "2017-06-30T06:20:30+02:00",
+        );
+        self.instant("2017-06-30 08:20:30 +0400", Uk, "2017-06-30T06:20:30+02:00");
+        self.instant("2017-06-30T08:20:30Z", Uk, "2017-06-30T10:20:30+02:00");
+        self.assert("2017-06-30T08:20:30", Uk, "2017-06-30T08:20:30+02:00");
+        self.assert("2017-06-30 12.20", Uk, "2017-06-30T12:20:00+02:00");
+        self.assert("2017-06-30 8.20", Uk, "2017-06-30T08:20:00+02:00");
+        self.assert("2017-06-30 12.15am", Uk, "2017-06-30T00:15:00+02:00");
+        self.assert("2017-06-30 12.25pm", Uk, "2017-06-30T12:25:00+02:00");
+        self.assert("2017-06-30 12:30pm", Uk, "2017-06-30T12:30:00+02:00");
+        self.assert("2017-06-30 8.30pm", Uk, "2017-06-30T20:30:00+02:00");
+        self.assert("2017-06-30 8:30pm", Uk, "2017-06-30T20:30:00+02:00");
+        self.assert("2017-06-30 2am", Uk, "2017-06-30T02:00:00+02:00");
+        self.assert("30 June 2018", Uk, "2018-06-30T00:00:00+02:00");
+        self.assert("June 30, 2018", Uk, "2018-06-30T00:00:00+02:00");
+        self.assert("June   30,    2018", Uk, "2018-06-30T00:00:00+02:00");
+
+        // named timezones and offsets
+        self.instant("3pm PST", Uk, "2018-03-22T01:00:00+02:00");
+        self.instant("09:00 CET", Uk, "2018-03-21T10:00:00+02:00");
+        self.instant("noon UTC", Uk, "2018-03-21T14:00:00+02:00");
+        self.instant("12:00 GMT+1", Uk, "2018-03-21T13:00:00+02:00");
+        self.instant("10:30 utc-05:30", Uk, "2018-03-21T18:00:00+02:00");
+        self.instant("9am +02", Uk, "2018-03-21T09:00:00+02:00");
+        self.instant("9am -0130", Uk, "2018-03-21T12:30:00+02:00");
+        self.instant("2017-06-30 08:20 +04:00", Uk, "2017-06-30T06:20:00+02:00");
+        self.instant("friday 8pm EST", Uk, "2018-03-24T03:00:00+02:00");
+        self.assert("tomorrow noon", Uk, "2018-03-22T12:00:00+02:00");
+        self.assert("midnight", Uk, "2018-03-21T00:00:00+02:00");
+        self.assert("friday 9AM", Uk, "2018-03-23T09:00:00+02:00");
+        // 'on' a date, 'at' a time and 'in' a duration read like the same text without them
+        self.assert("friday at 9am", Uk, "2018-03-23T09:00:00+02:00");
+        self.assert("yesterday at 17:00", Uk, "2018-03-20T17:00:00+02:00");
+        self.assert("at noon", Uk, "2018-03-21T12:00:00+02:00");
+        self.assert("on 4 July 2018", Uk, "2018-07-04T00:00:00+02:00");
+        self.assert("on July 4, 2018 at 8:05pm", Us, "2018-07-04T20:05:00+02:00");
+        self.assert("in 2 hours", Uk, "2018-03-21T13:00:00+02:00");
+        self.assert("in 1 hour 30 minutes", Uk, "2018-03-21T12:30:00+02:00");
+        self.assert("july at 9am", Uk, "2018-07-01T09:00:00+02:00");
+        self.assert("4 july at 8pm", Uk, "2018-07-04T20:00:00+02:00");
+        assert!(parse_date_string("in friday", self.base.clone(), Uk).is_err());
+        assert!(parse_date_string("friday at", self.base.clone(), Uk).is_err());
+        // ambiguous abbreviations depend on the dialect
+        self.instant("10:00 CST", Us, "2018-03-21T18:00:00+02:00");
+        self.instant("10:00 CST", Uk, "2018-03-21T04:00:00+02:00");
+        self.instant("10:00 IST", Us, "2018-03-21T06:30:00+02:00");
+        self.instant("10:00 IST", Uk, "2018-03-21T11:00:00+02:00");
+        assert!(parse_date_string("10:00 XYZ", self.base.clone(), Uk).is_err());
+
+        // explicit offsets give the exact instant, even when the local date differs
+        self.instant("2018-03-21 23:30 +05:30", Uk, "2018-03-21T20:00:00+02:00");
+        self.instant("2018-03-22 00:30 -10:00", Uk, "2018-03-22T12:30:00+02:00");
+        self.instant("2018-03-21 01:00 +14:00", Uk, "2018-03-20T13:00:00+02:00");
+        // and are never ambiguous
+        let reject = Options::new(Uk).disambiguation(Disambiguation::Reject);
+        self.check(
+            "2018-03-25 01:30 +01:00",
+            reject,
+            true,
+            "2018-03-25T02:30:00+02:00",
+        );
+
+        // unix timestamps, given in the base timezone
+        self.instant("@0", Uk, "1970-01-01T02:00:00+02:00");
+        self.instant("@1521622800", Uk, "2018-03-21T11:00:00+02:00");
+        self.instant("@1700000000", Us, "2023-11-15T00:13:20+02:00");
+        self.instant("@-1", Uk, "1970-01-01T01:59:59+02:00");
+
+        // bare timestamps in seconds, milliseconds and microseconds
+        let bare = Options::new(Uk).bare_timestamps(true);
+        self.check("1700000000", bare, true, "2023-11-15T00:13:20+02:00");
+        self.check("1700000000000", bare, true, "2023-11-15T00:13:20+02:00");
+        self.check("1700000000000000", bare, true, "2023-11-15T00:13:20+02:00");
+
+        // fractional timestamps, to the microsecond
+        self.instant("@1700000000.5", Uk, "2023-11-15T00:13:20.5+02:00");
+        self.instant(
+            "@1700000000.123456789",
+            Uk,
+            "2023-11-15T00:13:20.123456+02:00",
+        );
+        self.instant("@-1.25", Uk, "1970-01-01T01:59:58.75+02:00");
+        self.check("1700000000123", bare, true, "2023-11-15T00:13:20.123+02:00");
+        self.check(
+            "1700000000123456",
+            bare,
+            true,
+            "2023-11-15T00:13:20.123456+02:00",
+        );
+        // short numbers are still years
+        self.check("2017", bare, false, "2017-01-01T00:00:00+02:00");
+        assert!(parse_date_string("1700000000", self.base.clone(), Uk).is_err());
+        assert!(parse_date_string("@1700000000 9am", self.base.clone(), Uk).is_err());
+        // timestamps billions of years away, past the end of the calendar, give an error,
+        // or an instant without a date for types that go further, like `SystemTime`
+        for input in ["@99999999999999999", "@-99999999999999999"] {
+            if let Ok(date) = parse_date_string(input, self.base.clone(), Uk) {
+                let result = parse_date_string("tomorrow", date, Uk);
+                assert!(
+                    result.is_err(),
+                    "{input:?} should be out of range, got {result:?}"
+                );
+            }
+        }
+    }
+
+    /// IANA timezone names in the input, with the DST rules for that date.
+    ///
+    /// This needs interim's `chrono_tz_0_10` or `jiff_0_2` feature to look up the names, for any
+    /// date-time type. chrono-tz compiles the timezone database in, while jiff reads the system's
+    /// database at runtime unless its `tzdb-bundle-always` feature is enabled, so with only
+    /// `jiff_0_2` these checks fail on systems without one.
+    #[cfg(any(feature = "jiff_0_2", feature = "chrono_tz_0_10"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "jiff_0_2", feature = "chrono_tz_0_10")))
+    )]
+    pub fn named_zones(&self) {
+        use Dialect::{Uk, Us};
+
+        self.instant("3pm Europe/London", Uk, "2018-03-21T17:00:00+02:00");
+        self.instant(
+            "2018-07-01 3pm Europe/London",
+            Uk,
+            "2018-07-01T16:00:00+02:00",
+        );
+        self.instant(
+            "friday 3pm America/New_York",
+            Us,
+            "2018-03-23T21:00:00+02:00",
+        );
+        self.instant("tomorrow 9am in Tokyo", Uk, "2018-03-22T02:00:00+02:00");
+        self.instant("9am in new york", Us, "2018-03-21T15:00:00+02:00");
+        self.instant("9am in Europe/London", Uk, "2018-03-21T11:00:00+02:00");
+        self.instant("9am in Buenos Aires", Uk, "2018-03-21T14:00:00+02:00");
+        self.instant(
+            "12:00 America/Argentina/Buenos_Aires",
+            Uk,
+            "2018-03-21T17:00:00+02:00",
+        );
+        // 01:30 doesn't exist in London on the 25th, so the offset from before the gap is used
+        self.instant(
+            "2018-03-25 01:30 Europe/London",
+            Uk,
+            "2018-03-25T03:30:00+02:00",
+        );
+        assert!(parse_date_string("9am Mars/Olympus_Mons", self.base.clone(), Uk).is_err());
+        assert!(parse_date_string("9am in Atlantis", self.base.clone(), Uk).is_err());
+    }
+
+    /// Local times in a DST gap or fold, given with a named timezone, honour [`Disambiguation`].
+    ///
+    /// Zoned types only, with the features described in [`Harness::named_zones`].
+    #[cfg(any(feature = "jiff_0_2", feature = "chrono_tz_0_10"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "jiff_0_2", feature = "chrono_tz_0_10")))
+    )]
+    pub fn disambiguation(&self) {
+        let with = |disambiguation| Options::new(Dialect::Uk).disambiguation(disambiguation);
+
+        // London skipped from 01:00 to 02:00 on the 25th of March 2018
+        let gap = "2018-03-25 01:30 Europe/London";
+        self.exact(
+            gap,
+            with(Disambiguation::Compatible),
+            "2018-03-25T03:30:00+02:00",
+        );
+        self.exact(
+            gap,
+            with(Disambiguation::Earlier),
+            "2018-03-25T02:30:00+02:00",
+        );
+        self.exact(
+            gap,
+            with(Disambiguation::Later),
+            "2018-03-25T03:30:00+02:00",
+        );
+
+        // and repeated 01:00 to 02:00 on the 28th of October 2018
+        let fold = "2018-10-28 01:30 Europe/London";
+        self.exact(
+            fold,
+            with(Disambiguation::Compatible),
+            "2018-10-28T02:30:00+02:00",
+        );
+        self.exact(
+            fold,
+            with(Disambiguation::Earlier),
+            "2018-10-28T02:30:00+02:00",
+        );
+        self.exact(
+            fold,
+            with(Disambiguation::Later),
+            "2018-10-28T03:30:00+02:00",
+        );
+
+        let reject = with(Disambiguation::Reject);
+        assert_eq!(
+            parse_date_string_with(gap, self.base.clone(), reject).unwrap_err(),
+            DateError::NonexistentTime
+        );
+        assert_eq!(
+            parse_date_string_with(fold, self.base.clone(), reject).unwrap_err(),
+            DateError::AmbiguousTime
+        );
+        self.exact(
+            "2018-03-25 03:30 Europe/London",
+            with(Disambiguation::Reject),
+            "2018-03-25T04:30:00+02:00",
+        );
+    }
+
+    /// [`Options::keep_offset`] gives the result in the offset from the input.
+    ///
+    /// Zoned types only, which must be able to represent any fixed offset.
+    pub fn keep_offset(&self) {
+        let keep = Options::new(Dialect::Uk).keep_offset(true);
+        self.exact("9am +05:30", keep, "2018-03-21T09:00:00+05:30");
+        self.exact("9am PST", keep, "2018-03-21T09:00:00-08:00");
+        self.exact("tomorrow 23:30 -10:00", keep, "2018-03-22T23:30:00-10:00");
+        self.exact("2018-03-21 01:00 +14:00", keep, "2018-03-21T01:00:00+14:00");
+        // an explicit conversion still wins
+        self.exact("9am +05:30 to UTC", keep, "2018-03-21T03:30:00+00:00");
+        // times without a zone are unaffected
+        self.exact("9am", keep, "2018-03-21T09:00:00+02:00");
+        self.exact("@0", keep, "1970-01-01T02:00:00+02:00");
+    }
+
+    /// Conversions with a trailing `in <zone>` or `to <zone>`.
+    ///
+    /// Zoned types only, which must be able to represent any fixed offset.
+    pub fn conversion(&self) {
+        use Dialect::{Uk, Us};
+        let (uk, us) = (Options::new(Uk), Options::new(Us));
+
+        self.exact("now in UTC", uk, "2018-03-21T09:00:00+00:00");
+        self.exact("9am PST in CET", uk, "2018-03-21T18:00:00+01:00");
+        self.exact("9am to +05:30", uk, "2018-03-21T12:30:00+05:30");
+        self.exact("tomorrow to EST", us, "2018-03-22T04:00:00-05:00");
+        self.exact("@1700000000 in UTC", uk, "2023-11-14T22:13:20+00:00");
+        self.exact("3h in UTC", uk, "2018-03-21T12:00:00+00:00");
+
+        // a time without a zone is read in the zone after 'in'
+        self.exact("9am in CET", uk, "2018-03-21T10:00:00+02:00");
+        self.exact("9am in CET to UTC", uk, "2018-03-21T08:00:00+00:00");
+
+        assert!(parse_date_string("now in", self.base.clone(), Uk).is_err());
+        assert!(parse_date_string("9am PST bananas", self.base.clone(), Uk).is_err());
+    }
+
+    /// Conversions to IANA timezones, with [`Options::keep_offset`] or a trailing `in <zone>`.
+    ///
+    /// Zoned types only, which must be able to represent the named zones, with the features
+    /// described in [`Harness::named_zones`].
+    #[cfg(any(feature = "jiff_0_2", feature = "chrono_tz_0_10"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "jiff_0_2", feature = "chrono_tz_0_10")))
+    )]
+    pub fn named_zone_conversion(&self) {
+        let uk = Options::new(Dialect::Uk);
+        self.exact(
+            "2018-07-01 3pm Europe/London",
+            uk.keep_offset(true),
+            "2018-07-01T15:00:00+01:00",
+        );
+        self.exact(
+            "tomorrow 9am in Tokyo to UTC",
+            uk,
+            "2018-03-22T00:00:00+00:00",
+        );
+        self.exact(
+            "2018-07-01 12:00 UTC in Europe/London",
+            uk,
+            "2018-07-01T13:00:00+01:00",
+        );
+        self.exact(
+            "2018-03-01 12:00 UTC in London",
+            uk,
+            "2018-03-01T12:00:00+00:00",
+        );
+    }
+}
+
+/// Remove trailing zeros from the fractional seconds, and the point if they're all zero
+fn trim_fraction(formatted: &str) -> String {
+    let Some(rest) = formatted.get(19..).and_then(|rest| rest.strip_prefix('.')) else {
+        return formatted.to_owned();
+    };
+    let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let fraction = rest[..digits].trim_end_matches('0');
+    let point = if fraction.is_empty() { "" } else { "." };
+    format!("{}{point}{fraction}{}", &formatted[..19], &rest[digits..])
+}
+
+/// Convert a local `YYYY-MM-DDTHH:MM:SS` at `offset` seconds east of UTC to RFC 3339 in UTC
+fn to_utc(date_time: &str, offset: i32) -> String {
+    let num = |range: core::ops::Range<usize>| date_time[range].parse::<u8>().unwrap();
+    let date = civil::Date::new(date_time[0..4].parse().unwrap(), num(5..7), num(8..10));
+    let time = civil::Time::new(num(11..13), num(14..16), num(17..19));
+    let local = civil::DateTime::from_parts(date.unwrap(), time.unwrap(), offset).unwrap();
+    local.to_offset(0).unwrap().to_string()
+}
@@ -1,8 +1,25 @@
+use crate::tz::Zone;
+use crate::{DateResult, Disambiguation};
+
+pub mod v1;
+
+#[cfg(feature = "chrono_0_4")]
+pub use self::chrono::ChronoTimeZone;
+#[cfg(feature = "time_0_3")]
+pub use self::time::{Offsets, ZonedDateTime};
+
 mod sealed {
-    pub trait Sealed {}
+    pub trait Date {}
+    pub trait Time {}
+    pub trait DateTime {}
+    #[cfg(feature = "chrono_0_4")]
+    pub trait ChronoTimeZone {}
 }
 
-pub trait Date: Clone + PartialOrd + sealed::Sealed {
+/// A calendar date, implemented for the date types of the supported crates.
+///
+/// This trait is sealed. Implement [`v1::Date`] for other types.
+pub trait Date: Clone + PartialOrd + sealed::Date {
     #[doc(hidden)]
     fn from_ymd(year: i32, month: u8, day: u8) -> Option<Self>;
     #[doc(hidden)]
@@ -13,40 +30,318 @@ pub trait Date: Clone + PartialOrd + sealed::Sealed {
     #[doc(hidden)]
     fn year(&self) -> i32;
     #[doc(hidden)]
+    fn month(&self) -> u8;
+    #[doc(hidden)]
+    fn day(&self) -> u8;
+    #[doc(hidden)]
     fn weekday(&self) -> u8;
 }
 
-pub trait Time: Clone + PartialOrd + sealed::Sealed {
+/// A time of day, implemented for the time types of the supported crates.
+///
+/// This trait is sealed. Implement [`v1::Time`] for other types.
+pub trait Time: Clone + PartialOrd + sealed::Time {
     #[doc(hidden)]
     fn from_hms(h: u32, m: u32, s: u32) -> Option<Self>;
     #[doc(hidden)]
     fn with_micros(self, ms: u32) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn hour(&self) -> u8;
+    #[doc(hidden)]
+    fn minute(&self) -> u8;
+    #[doc(hidden)]
+    fn second(&self) -> u8;
+    #[doc(hidden)]
+    fn microsecond(&self) -> u32;
 }
 
-pub trait DateTime: Sized + sealed::Sealed {
+/// A date and time, implemented for the date-time types of the supported crates.
+///
+/// This trait is sealed. Implement [`v1::DateTime`] for other types.
+pub trait DateTime: Sized + sealed::DateTime {
     type TimeZone;
     type Date: Date;
     type Time: Time;
 
     #[doc(hidden)]
-    fn new(tz: Self::TimeZone, date: Self::Date, time: Self::Time) -> Self;
+    fn new(
+        tz: Self::TimeZone,
+        date: Self::Date,
+        time: Self::Time,
+        disambiguation: Disambiguation,
+    ) -> DateResult<Self>;
     #[doc(hidden)]
-    fn split(self) -> (Self::TimeZone, Self::Date, Self::Time);
+    fn split(self) -> Option<(Self::TimeZone, Self::Date, Self::Time)>;
     #[doc(hidden)]
-    fn with_offset(self, secs: i64) -> Option<Self>;
+    fn from_offset(
+        tz: Self::TimeZone,
+        date: Self::Date,
+        time: Self::Time,
+        offset: i64,
+    ) -> DateResult<Self>;
     #[doc(hidden)]
     fn offset_seconds(self, secs: i64) -> Option<Self>;
+    #[doc(hidden)]
+    fn to_zone(self, zone: &Zone) -> Option<Self>;
+    #[doc(hidden)]
+    fn utc_offset(&self) -> Option<i64>;
 }
 
-#[cfg(feature = "chrono_0_4")]
-mod chrono {
-    use chrono::{Duration, NaiveDate, NaiveTime, Offset, TimeZone, Timelike};
+mod civil {
+    // the built-in calendar uses the public traits, as an example for other types
+    use super::v1::{Date, DateTime, Time, Zone};
+    use crate::civil;
+    use crate::{DateError, DateResult, Disambiguation};
+
+    impl Date for civil::Date {
+        fn from_ymd(year: i32, month: u8, day: u8) -> Option<Self> {
+            civil::Date::new(year, month, day)
+        }
+
+        fn offset_months(self, months: i32) -> Option<Self> {
+            let months = self.year() as i64 * 12 + (self.month() - 1) as i64 + months as i64;
+            let year = i32::try_from(months.div_euclid(12)).ok()?;
+            let month = months.rem_euclid(12) as u8 + 1;
+            let day = self.day().min(civil::days_in_month(year, month));
+            civil::Date::new(year, month, day)
+        }
+
+        fn offset_days(self, days: i64) -> Option<Self> {
+            civil::Date::from_days(self.to_days().checked_add(days)?)
+        }
+
+        fn year(&self) -> i32 {
+            civil::Date::year(*self)
+        }
+
+        fn month(&self) -> u8 {
+            civil::Date::month(*self)
+        }
+
+        fn day(&self) -> u8 {
+            civil::Date::day(*self)
+        }
+
+        fn weekday(&self) -> u8 {
+            civil::Date::weekday(*self)
+        }
+    }
+
+    impl Time for civil::Time {
+        fn from_hms(h: u32, m: u32, s: u32) -> Option<Self> {
+            civil::Time::new(
+                u8::try_from(h).ok()?,
+                u8::try_from(m).ok()?,
+                u8::try_from(s).ok()?,
+            )
+        }
+
+        fn with_micros(self, ms: u32) -> Option<Self> {
+            self.with_microsecond(ms)
+        }
 
-    impl super::sealed::Sealed for NaiveDate {}
-    impl super::sealed::Sealed for NaiveTime {}
-    impl<Tz: TimeZone> super::sealed::Sealed for chrono::DateTime<Tz> {}
+        fn hour(&self) -> u8 {
+            civil::Time::hour(*self)
+        }
+
+        fn minute(&self) -> u8 {
+            civil::Time::minute(*self)
+        }
+
+        fn second(&self) -> u8 {
+            civil::Time::second(*self)
+        }
+
+        fn microsecond(&self) -> u32 {
+            civil::Time::microsecond(*self)
+        }
+    }
+
+    impl DateTime for civil::DateTime {
+        type TimeZone = i32;
+        type Date = civil::Date;
+        type Time = civil::Time;
+
+        fn from_local(
+            tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            _disambiguation: Disambiguation,
+        ) -> DateResult<Self> {
+            // a fixed offset has no gaps or overlaps
+            civil::DateTime::from_parts(date, time, tz).ok_or(DateError::UnsupportedTimeZone)
+        }
+
+        fn split(self) -> (Self::TimeZone, Self::Date, Self::Time) {
+            (self.offset(), self.date(), self.time())
+        }
+
+        fn from_offset(
+            tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            offset: i64,
+        ) -> DateResult<Self> {
+            i32::try_from(offset)
+                .ok()
+                .and_then(|offset| civil::DateTime::from_parts(date, time, offset))
+                .ok_or(DateError::UnsupportedTimeZone)?
+                .to_offset(tz)
+                .ok_or(DateError::MissingDate)
+        }
+
+        fn offset_seconds(self, secs: i64) -> Option<Self> {
+            let unix = self.unix_timestamp().checked_add(secs)?;
+            civil::DateTime::from_unix(unix, self.time().microsecond(), self.offset())
+        }
+
+        fn to_zone(self, zone: Zone<'_>) -> Option<Self> {
+            let offset = zone.offset_at(self.unix_timestamp())?;
+            self.to_offset(i32::try_from(offset).ok()?)
+        }
+
+        fn utc_offset(&self) -> Option<i64> {
+            Some(civil::DateTime::offset(*self).into())
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+mod std {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use super::{DateTime, Zone};
+    use crate::civil;
+    use crate::{DateError, DateResult, Disambiguation};
+
+    impl super::sealed::DateTime for SystemTime {}
+
+    fn to_civil(time: SystemTime) -> Option<civil::DateTime> {
+        let (secs, nanos) = match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => (i64::try_from(after.as_secs()).ok()?, after.subsec_nanos()),
+            Err(before) => {
+                let before = before.duration();
+                let secs = -i64::try_from(before.as_secs()).ok()?;
+                match before.subsec_nanos() {
+                    0 => (secs, 0),
+                    nanos => (secs - 1, 1_000_000_000 - nanos),
+                }
+            }
+        };
+        civil::DateTime::from_unix(secs, nanos / 1000, 0)
+    }
+
+    fn from_civil(datetime: civil::DateTime) -> Option<SystemTime> {
+        let secs = datetime.unix_timestamp();
+        let micros = Duration::from_micros(datetime.time().microsecond() as u64);
+        if secs >= 0 {
+            UNIX_EPOCH.checked_add(Duration::from_secs(secs as u64) + micros)
+        } else {
+            UNIX_EPOCH
+                .checked_sub(Duration::from_secs(secs.unsigned_abs()))?
+                .checked_add(micros)
+        }
+    }
+
+    /// An instant, with calendar operations in UTC
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    impl DateTime for SystemTime {
+        type TimeZone = ();
+        type Date = civil::Date;
+        type Time = civil::Time;
+
+        fn new(
+            _tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            _disambiguation: Disambiguation,
+        ) -> DateResult<Self> {
+            civil::DateTime::from_parts(date, time, 0)
+                .and_then(from_civil)
+                .ok_or(DateError::MissingDate)
+        }
+
+        fn split(self) -> Option<(Self::TimeZone, Self::Date, Self::Time)> {
+            // the civil calendar doesn't reach this far from the unix epoch
+            let datetime = to_civil(self)?;
+            Some(((), datetime.date(), datetime.time()))
+        }
+
+        fn from_offset(
+            _tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            offset: i64,
+        ) -> DateResult<Self> {
+            i32::try_from(offset)
+                .ok()
+                .and_then(|offset| civil::DateTime::from_parts(date, time, offset))
+                .ok_or(DateError::UnsupportedTimeZone)
+                .and_then(|datetime| from_civil(datetime).ok_or(DateError::MissingDate))
+        }
+
+        fn offset_seconds(self, secs: i64) -> Option<Self> {
+            if secs >= 0 {
+                self.checked_add(Duration::from_secs(secs as u64))
+            } else {
+                self.checked_sub(Duration::from_secs(secs.unsigned_abs()))
+            }
+        }
+
+        fn to_zone(self, _zone: &Zone) -> Option<Self> {
+            // the instant is the same in every timezone
+            Some(self)
+        }
+
+        fn utc_offset(&self) -> Option<i64> {
+            Some(0)
+        }
+    }
+}
+
+#[cfg(feature = "chrono_0_4")]
+pub(crate) mod chrono {
+    use chrono::{
+        FixedOffset, LocalResult, NaiveDate, NaiveDateTime, NaiveTime, Offset, TimeDelta, TimeZone,
+        Timelike, Utc,
+    };
+
+    impl super::sealed::Date for NaiveDate {}
+    impl super::sealed::Time for NaiveTime {}
+    impl<Tz: ChronoTimeZone> super::sealed::DateTime for chrono::DateTime<Tz> {}
+    impl super::sealed::ChronoTimeZone for Utc {}
+    impl super::sealed::ChronoTimeZone for FixedOffset {}
+    #[cfg(feature = "std")]
+    impl super::sealed::ChronoTimeZone for chrono::Local {}
+    #[cfg(feature = "chrono_tz_0_10")]
+    impl super::sealed::ChronoTimeZone for chrono_tz::Tz {}
+    impl super::sealed::DateTime for NaiveDateTime {}
+
+    use super::{Date, DateTime, Time, Zone};
+    use crate::tz::{resolve_fold, resolve_gap};
+    use crate::{DateError, DateResult, Disambiguation};
+
+    /// The date-time in `tz` with the given local date and time
+    pub(crate) fn from_local<Tz: TimeZone>(
+        tz: &Tz,
+        datetime: NaiveDateTime,
+        disambiguation: Disambiguation,
+    ) -> DateResult<chrono::DateTime<Tz>> {
+        match tz.from_local_datetime(&datetime) {
+            LocalResult::Single(dt) => Ok(dt),
+            LocalResult::Ambiguous(earlier, later) => resolve_fold(earlier, later, disambiguation),
+            LocalResult::None => {
+                // DST transitions are months apart, so the offsets a day either side
+                // are the offsets before and after the gap
+                let before = tz.offset_from_utc_datetime(&(datetime - TimeDelta::days(1)));
+                let after = tz.offset_from_utc_datetime(&(datetime + TimeDelta::days(1)));
+                let offset = resolve_gap(before.fix(), after.fix(), disambiguation)?;
+                Ok(tz.from_utc_datetime(&(datetime - offset)))
+            }
+        }
+    }
 
-    use super::{Date, DateTime, Time};
     #[cfg_attr(docsrs, doc(cfg(feature = "chrono_0_4")))]
     impl Date for NaiveDate {
         fn from_ymd(year: i32, month: u8, day: u8) -> Option<Self> {
@@ -62,13 +357,21 @@ mod chrono {
         }
 
         fn offset_days(self, days: i64) -> Option<Self> {
-            self.checked_add_signed(Duration::days(days))
+            self.checked_add_signed(TimeDelta::try_days(days)?)
         }
 
         fn year(&self) -> i32 {
             chrono::Datelike::year(self)
         }
 
+        fn month(&self) -> u8 {
+            chrono::Datelike::month(self) as u8
+        }
+
+        fn day(&self) -> u8 {
+            chrono::Datelike::day(self) as u8
+        }
+
         fn weekday(&self) -> u8 {
             chrono::Datelike::weekday(self).num_days_from_monday() as u8
         }
@@ -82,46 +385,180 @@ mod chrono {
         fn with_micros(self, ms: u32) -> Option<Self> {
             self.with_nanosecond(ms.checked_mul(1_000)?)
         }
+
+        fn hour(&self) -> u8 {
+            Timelike::hour(self) as u8
+        }
+
+        fn minute(&self) -> u8 {
+            Timelike::minute(self) as u8
+        }
+
+        fn second(&self) -> u8 {
+            Timelike::second(self) as u8
+        }
+
+        fn microsecond(&self) -> u32 {
+            // leap seconds are counted in the nanoseconds
+            Timelike::nanosecond(self) % 1_000_000_000 / 1_000
+        }
+    }
+
+    /// A chrono timezone, for [`DateTime`] on `chrono::DateTime`. Implemented for `Utc`,
+    /// `FixedOffset`, `Local` with the `std` feature, and `chrono_tz::Tz` with the
+    /// `chrono_tz_0_10` feature.
+    ///
+    /// This trait is sealed.
+    pub trait ChronoTimeZone: TimeZone + super::sealed::ChronoTimeZone {
+        /// This timezone for the instant `timestamp` in `zone`, or `None` if it can't be
+        /// built from another timezone
+        #[doc(hidden)]
+        fn from_zone(zone: &Zone, timestamp: i64) -> Option<Self>;
+    }
+
+    impl ChronoTimeZone for Utc {
+        fn from_zone(_zone: &Zone, _timestamp: i64) -> Option<Self> {
+            // the instant is the same in every timezone
+            Some(Utc)
+        }
+    }
+
+    impl ChronoTimeZone for FixedOffset {
+        fn from_zone(zone: &Zone, timestamp: i64) -> Option<Self> {
+            FixedOffset::east_opt(i32::try_from(zone.offset_at(timestamp)?).ok()?)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl ChronoTimeZone for chrono::Local {
+        fn from_zone(_zone: &Zone, _timestamp: i64) -> Option<Self> {
+            None
+        }
+    }
+
+    #[cfg(feature = "chrono_tz_0_10")]
+    impl ChronoTimeZone for chrono_tz::Tz {
+        fn from_zone(zone: &Zone, _timestamp: i64) -> Option<Self> {
+            match zone {
+                Zone::Fixed(0) => Some(chrono_tz::Tz::UTC),
+                _ => zone.iana_name()?.parse().ok(),
+            }
+        }
     }
 
+    /// Conversions to another timezone are supported for `Utc`, `FixedOffset`, and
+    /// `chrono_tz::Tz`, while `Local` gives [`DateError::UnsupportedTimeZone`].
     #[cfg_attr(docsrs, doc(cfg(feature = "chrono_0_4")))]
-    impl<Tz: TimeZone> DateTime for chrono::DateTime<Tz> {
+    impl<Tz: ChronoTimeZone> DateTime for chrono::DateTime<Tz> {
         type TimeZone = Tz;
         type Date = NaiveDate;
         type Time = NaiveTime;
 
-        fn new(tz: Self::TimeZone, date: Self::Date, time: Self::Time) -> Self {
-            let datetime = date.and_time(time);
-            let offset = tz.offset_from_utc_datetime(&datetime);
-            Self::from_naive_utc_and_offset(datetime - offset.fix(), offset)
+        fn new(
+            tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            disambiguation: Disambiguation,
+        ) -> DateResult<Self> {
+            from_local(&tz, date.and_time(time), disambiguation)
         }
 
-        fn split(self) -> (Self::TimeZone, Self::Date, Self::Time) {
-            (self.timezone(), self.date_naive(), self.time())
+        fn split(self) -> Option<(Self::TimeZone, Self::Date, Self::Time)> {
+            Some((self.timezone(), self.date_naive(), self.time()))
+        }
+
+        fn from_offset(
+            tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            offset: i64,
+        ) -> DateResult<Self> {
+            let utc = date
+                .and_time(time)
+                .checked_sub_signed(TimeDelta::seconds(offset))
+                .ok_or(DateError::MissingDate)?;
+            Ok(tz.from_utc_datetime(&utc))
+        }
+
+        fn offset_seconds(self, secs: i64) -> Option<Self> {
+            self.checked_add_signed(TimeDelta::try_seconds(secs)?)
+        }
+
+        fn to_zone(self, zone: &Zone) -> Option<Self> {
+            let tz = Tz::from_zone(zone, self.timestamp())?;
+            Some(self.with_timezone(&tz))
         }
 
-        fn with_offset(self, secs: i64) -> Option<Self> {
-            let offset = self
-                .timezone()
-                .offset_from_utc_date(&self.date_naive())
-                .fix()
-                .local_minus_utc() as i64;
-            self.offset_seconds(offset - secs)
+        fn utc_offset(&self) -> Option<i64> {
+            Some(
+                chrono::DateTime::offset(self)
+                    .fix()
+                    .local_minus_utc()
+                    .into(),
+            )
+        }
+    }
+
+    /// A date and time without a timezone.
+    ///
+    /// Inputs with a timezone, an offset or a unix timestamp describe an instant,
+    /// which can't be represented, so they give [`DateError::UnsupportedTimeZone`].
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono_0_4")))]
+    impl DateTime for NaiveDateTime {
+        type TimeZone = ();
+        type Date = NaiveDate;
+        type Time = NaiveTime;
+
+        fn new(
+            _tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            _disambiguation: Disambiguation,
+        ) -> DateResult<Self> {
+            Ok(date.and_time(time))
+        }
+
+        fn split(self) -> Option<(Self::TimeZone, Self::Date, Self::Time)> {
+            Some(((), self.date(), self.time()))
+        }
+
+        fn from_offset(
+            _tz: Self::TimeZone,
+            _date: Self::Date,
+            _time: Self::Time,
+            _offset: i64,
+        ) -> DateResult<Self> {
+            Err(DateError::UnsupportedTimeZone)
         }
 
         fn offset_seconds(self, secs: i64) -> Option<Self> {
-            self.checked_add_signed(Duration::seconds(secs))
+            self.checked_add_signed(TimeDelta::try_seconds(secs)?)
+        }
+
+        fn to_zone(self, _zone: &Zone) -> Option<Self> {
+            None
+        }
+
+        fn utc_offset(&self) -> Option<i64> {
+            None
         }
     }
 }
 
 #[cfg(feature = "time_0_3")]
 mod time {
-    use super::{Date, DateTime, Time};
+    use core::fmt;
+
+    use super::{Date, DateTime, Time, Zone};
+    use crate::tz::{resolve_fold, resolve_gap};
+    use crate::{DateError, DateResult, Disambiguation};
 
-    impl super::sealed::Sealed for time::Date {}
-    impl super::sealed::Sealed for time::Time {}
-    impl super::sealed::Sealed for time::OffsetDateTime {}
+    impl super::sealed::Date for time::Date {}
+    impl super::sealed::Time for time::Time {}
+    impl super::sealed::DateTime for time::OffsetDateTime {}
+    impl super::sealed::DateTime for time::PrimitiveDateTime {}
+    impl super::sealed::DateTime for time::UtcDateTime {}
+    impl<F> super::sealed::DateTime for ZonedDateTime<F> {}
 
     #[cfg_attr(docsrs, doc(cfg(feature = "time_0_3")))]
     impl Date for time::Date {
@@ -154,12 +591,20 @@ mod time {
         }
 
         fn offset_days(self, days: i64) -> Option<Self> {
-            self.checked_add(time::Duration::days(days))
+            days.checked_mul(86_400)
+                .map(time::Duration::seconds)
+                .and_then(|duration| self.checked_add(duration))
         }
 
         fn year(&self) -> i32 {
             time::Date::year(*self)
         }
+        fn month(&self) -> u8 {
+            time::Date::month(*self) as u8
+        }
+        fn day(&self) -> u8 {
+            time::Date::day(*self)
+        }
         fn weekday(&self) -> u8 {
             time::Date::weekday(*self).number_days_from_monday()
         }
@@ -179,6 +624,22 @@ mod time {
         fn with_micros(self, ms: u32) -> Option<Self> {
             self.replace_microsecond(ms).ok()
         }
+
+        fn hour(&self) -> u8 {
+            time::Time::hour(*self)
+        }
+
+        fn minute(&self) -> u8 {
+            time::Time::minute(*self)
+        }
+
+        fn second(&self) -> u8 {
+            time::Time::second(*self)
+        }
+
+        fn microsecond(&self) -> u32 {
+            time::Time::microsecond(*self)
+        }
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "time_0_3")))]
@@ -187,22 +648,304 @@ mod time {
         type Date = time::Date;
         type Time = time::Time;
 
-        fn new(tz: Self::TimeZone, date: Self::Date, time: Self::Time) -> Self {
-            time::PrimitiveDateTime::new(date, time).assume_offset(tz)
+        fn new(
+            tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            _disambiguation: Disambiguation,
+        ) -> DateResult<Self> {
+            // a fixed offset has no gaps or overlaps
+            Ok(time::PrimitiveDateTime::new(date, time).assume_offset(tz))
         }
 
-        fn split(self) -> (Self::TimeZone, Self::Date, Self::Time) {
-            (self.offset(), self.date(), self.time())
+        fn split(self) -> Option<(Self::TimeZone, Self::Date, Self::Time)> {
+            Some((self.offset(), self.date(), self.time()))
+        }
+
+        fn from_offset(
+            tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            offset: i64,
+        ) -> DateResult<Self> {
+            // the same local time in `tz` is off by the difference in offsets
+            time::PrimitiveDateTime::new(date, time)
+                .assume_offset(tz)
+                .offset_seconds(tz.whole_seconds() as i64 - offset)
+                .ok_or(DateError::MissingDate)
+        }
+
+        fn offset_seconds(self, secs: i64) -> Option<Self> {
+            self.checked_add(time::Duration::seconds(secs))
+        }
+
+        fn to_zone(self, zone: &Zone) -> Option<Self> {
+            let offset = zone.offset_at(self.unix_timestamp())?;
+            let offset = time::UtcOffset::from_whole_seconds(i32::try_from(offset).ok()?).ok()?;
+            Some(self.to_offset(offset))
+        }
+
+        fn utc_offset(&self) -> Option<i64> {
+            Some(time::OffsetDateTime::offset(*self).whole_seconds().into())
+        }
+    }
+
+    /// A date and time without a timezone.
+    ///
+    /// Inputs with a timezone, an offset or a unix timestamp describe an instant,
+    /// which can't be represented, so they give [`DateError::UnsupportedTimeZone`].
+    #[cfg_attr(docsrs, doc(cfg(feature = "time_0_3")))]
+    impl DateTime for time::PrimitiveDateTime {
+        type TimeZone = ();
+        type Date = time::Date;
+        type Time = time::Time;
+
+        fn new(
+            _tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            _disambiguation: Disambiguation,
+        ) -> DateResult<Self> {
+            Ok(time::PrimitiveDateTime::new(date, time))
+        }
+
+        fn split(self) -> Option<(Self::TimeZone, Self::Date, Self::Time)> {
+            Some(((), self.date(), self.time()))
         }
 
-        fn with_offset(self, secs: i64) -> Option<Self> {
-            let offset = self.offset().whole_seconds() as i64;
-            self.offset_seconds(offset - secs)
+        fn from_offset(
+            _tz: Self::TimeZone,
+            _date: Self::Date,
+            _time: Self::Time,
+            _offset: i64,
+        ) -> DateResult<Self> {
+            Err(DateError::UnsupportedTimeZone)
         }
 
         fn offset_seconds(self, secs: i64) -> Option<Self> {
             self.checked_add(time::Duration::seconds(secs))
         }
+
+        fn to_zone(self, _zone: &Zone) -> Option<Self> {
+            None
+        }
+
+        fn utc_offset(&self) -> Option<i64> {
+            None
+        }
+    }
+
+    /// An instant, with calendar operations in UTC
+    #[cfg_attr(docsrs, doc(cfg(feature = "time_0_3")))]
+    impl DateTime for time::UtcDateTime {
+        type TimeZone = ();
+        type Date = time::Date;
+        type Time = time::Time;
+
+        fn new(
+            _tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            _disambiguation: Disambiguation,
+        ) -> DateResult<Self> {
+            Ok(time::UtcDateTime::new(date, time))
+        }
+
+        fn split(self) -> Option<(Self::TimeZone, Self::Date, Self::Time)> {
+            Some(((), self.date(), self.time()))
+        }
+
+        fn from_offset(
+            _tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            offset: i64,
+        ) -> DateResult<Self> {
+            time::UtcDateTime::new(date, time)
+                .offset_seconds(-offset)
+                .ok_or(DateError::MissingDate)
+        }
+
+        fn offset_seconds(self, secs: i64) -> Option<Self> {
+            self.checked_add(time::Duration::seconds(secs))
+        }
+
+        fn to_zone(self, _zone: &Zone) -> Option<Self> {
+            // the instant is the same in every timezone
+            Some(self)
+        }
+
+        fn utc_offset(&self) -> Option<i64> {
+            Some(0)
+        }
+    }
+
+    /// A date and time in a timezone with daylight saving time.
+    ///
+    /// `OffsetDateTime` only has a fixed offset, so "tomorrow 9am" across a DST change
+    /// keeps today's offset. This keeps a function giving the zone's offset at any instant,
+    /// like one built on the `time-tz` or `tzdb` crates, and finds the offset in effect
+    /// for each computed local time. The function can be a closure that captures the zone.
+    ///
+    /// Conversions with a trailing `in <zone>` give the offset of that zone at the instant,
+    /// like `OffsetDateTime`, which is kept for any later calculations.
+    ///
+    /// ```
+    /// use interim::{datetime::ZonedDateTime, parse_date_string, Dialect};
+    /// use time::macros::{datetime, utc_datetime};
+    /// use time::{UtcDateTime, UtcOffset};
+    ///
+    /// // British Summer Time in 2024
+    /// fn london(utc: UtcDateTime) -> UtcOffset {
+    ///     let bst = utc_datetime!(2024-03-31 01:00)..utc_datetime!(2024-10-27 01:00);
+    ///     let hours = if bst.contains(&utc) { 1 } else { 0 };
+    ///     UtcOffset::from_hms(hours, 0, 0).unwrap()
+    /// }
+    ///
+    /// let now = ZonedDateTime::new(datetime!(2024-03-30 12:00 UTC), london);
+    /// let date = parse_date_string("tomorrow 9am", now, Dialect::Uk).unwrap();
+    /// assert_eq!(date.date_time(), datetime!(2024-03-31 09:00 +01:00));
+    /// ```
+    #[cfg_attr(docsrs, doc(cfg(feature = "time_0_3")))]
+    #[derive(Clone, Copy)]
+    pub struct ZonedDateTime<F = fn(time::UtcDateTime) -> time::UtcOffset> {
+        date_time: time::OffsetDateTime,
+        zone: Offsets<F>,
+    }
+
+    /// The timezone of a [`ZonedDateTime`]: the function giving its offsets,
+    /// or a fixed offset after a conversion to another zone
+    #[cfg_attr(docsrs, doc(cfg(feature = "time_0_3")))]
+    #[derive(Clone, Copy)]
+    pub struct Offsets<F> {
+        offset_at: F,
+        fixed: Option<time::UtcOffset>,
+    }
+
+    impl<F: Fn(time::UtcDateTime) -> time::UtcOffset> Offsets<F> {
+        fn offset_at(&self, utc: time::UtcDateTime) -> time::UtcOffset {
+            self.fixed.unwrap_or_else(|| (self.offset_at)(utc))
+        }
+    }
+
+    impl<F: Fn(time::UtcDateTime) -> time::UtcOffset> ZonedDateTime<F> {
+        /// The same instant as `date_time`, in the timezone whose offset from UTC
+        /// at each instant is given by `offset_at`
+        #[must_use]
+        pub fn new(date_time: time::OffsetDateTime, offset_at: F) -> Self {
+            let utc = time::UtcDateTime::from(date_time);
+            Self {
+                date_time: utc.to_offset(offset_at(utc)),
+                zone: Offsets {
+                    offset_at,
+                    fixed: None,
+                },
+            }
+        }
+
+        /// The date and time, at the offset in effect in the timezone
+        #[must_use]
+        pub const fn date_time(&self) -> time::OffsetDateTime {
+            self.date_time
+        }
+
+        fn at(utc: time::UtcDateTime, zone: Offsets<F>) -> Option<Self> {
+            Some(Self {
+                date_time: utc.checked_to_offset(zone.offset_at(utc))?,
+                zone,
+            })
+        }
+    }
+
+    impl<F> fmt::Debug for ZonedDateTime<F> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("ZonedDateTime")
+                .field("date_time", &self.date_time)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl<F> From<ZonedDateTime<F>> for time::OffsetDateTime {
+        fn from(date_time: ZonedDateTime<F>) -> Self {
+            date_time.date_time
+        }
+    }
+
+    #[cfg_attr(docsrs, doc(cfg(feature = "time_0_3")))]
+    impl<F: Fn(time::UtcDateTime) -> time::UtcOffset + Clone> DateTime for ZonedDateTime<F> {
+        type TimeZone = Offsets<F>;
+        type Date = time::Date;
+        type Time = time::Time;
+
+        fn new(
+            tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            disambiguation: Disambiguation,
+        ) -> DateResult<Self> {
+            let local = time::UtcDateTime::new(date, time);
+            // DST transitions are months apart, so the offsets a day either side
+            // are the offsets before and after any transition at this local time
+            let day = time::Duration::days(1);
+            let before = tz.offset_at(local.checked_sub(day).ok_or(DateError::MissingDate)?);
+            let after = tz.offset_at(local.checked_add(day).ok_or(DateError::MissingDate)?);
+
+            // the instant for the local time at `offset`, if the zone uses that offset then
+            let with = |offset: time::UtcOffset| {
+                let utc =
+                    local.checked_sub(time::Duration::seconds(offset.whole_seconds().into()))?;
+                (tz.offset_at(utc) == offset).then_some(utc)
+            };
+            let utc = match (with(before), with(after)) {
+                (Some(first), Some(second)) if first != second => {
+                    resolve_fold(first.min(second), first.max(second), disambiguation)?
+                }
+                (Some(utc), _) | (None, Some(utc)) => utc,
+                (None, None) => {
+                    let offset = resolve_gap(before, after, disambiguation)?;
+                    local
+                        .checked_sub(time::Duration::seconds(offset.whole_seconds().into()))
+                        .ok_or(DateError::MissingDate)?
+                }
+            };
+            Self::at(utc, tz).ok_or(DateError::MissingDate)
+        }
+
+        fn split(self) -> Option<(Self::TimeZone, Self::Date, Self::Time)> {
+            Some((self.zone, self.date_time.date(), self.date_time.time()))
+        }
+
+        fn from_offset(
+            tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            offset: i64,
+        ) -> DateResult<Self> {
+            time::UtcDateTime::new(date, time)
+                .checked_sub(time::Duration::seconds(offset))
+                .and_then(|utc| Self::at(utc, tz))
+                .ok_or(DateError::MissingDate)
+        }
+
+        fn offset_seconds(self, secs: i64) -> Option<Self> {
+            let utc = time::UtcDateTime::from(self.date_time);
+            Self::at(utc.checked_add(time::Duration::seconds(secs))?, self.zone)
+        }
+
+        fn to_zone(self, zone: &Zone) -> Option<Self> {
+            let utc = time::UtcDateTime::from(self.date_time);
+            let offset = zone.offset_at(utc.unix_timestamp())?;
+            let offset = time::UtcOffset::from_whole_seconds(i32::try_from(offset).ok()?).ok()?;
+            let zone = Offsets {
+                fixed: Some(offset),
+                ..self.zone
+            };
+            Self::at(utc, zone)
+        }
+
+        fn utc_offset(&self) -> Option<i64> {
+            Some(self.date_time.offset().whole_seconds().into())
+        }
     }
 }
 
@@ -211,11 +954,14 @@ mod jiff_0_1 {
     use jiff::Span;
     use jiff_0_1 as jiff;
 
-    use super::{Date, DateTime, Time};
+    use super::{Date, DateTime, Time, Zone};
+    use crate::{DateError, DateResult, Disambiguation};
 
-    impl super::sealed::Sealed for jiff::civil::Date {}
-    impl super::sealed::Sealed for jiff::civil::Time {}
-    impl super::sealed::Sealed for jiff::Zoned {}
+    impl super::sealed::Date for jiff::civil::Date {}
+    impl super::sealed::Time for jiff::civil::Time {}
+    impl super::sealed::DateTime for jiff::Zoned {}
+    impl super::sealed::DateTime for jiff::civil::DateTime {}
+    impl super::sealed::DateTime for jiff::Timestamp {}
 
     #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_1")))]
     impl Date for jiff::civil::Date {
@@ -224,17 +970,25 @@ mod jiff_0_1 {
         }
 
         fn offset_months(self, months: i32) -> Option<Self> {
-            self.checked_add(Span::new().months(months)).ok()
+            self.checked_add(Span::new().try_months(months).ok()?).ok()
         }
 
         fn offset_days(self, days: i64) -> Option<Self> {
-            self.checked_add(Span::new().days(days)).ok()
+            self.checked_add(Span::new().try_days(days).ok()?).ok()
         }
 
         fn year(&self) -> i32 {
             jiff::civil::Date::year(*self) as i32
         }
 
+        fn month(&self) -> u8 {
+            jiff::civil::Date::month(*self) as u8
+        }
+
+        fn day(&self) -> u8 {
+            jiff::civil::Date::day(*self) as u8
+        }
+
         fn weekday(&self) -> u8 {
             jiff::civil::Date::weekday(*self).to_monday_zero_offset() as u8
         }
@@ -257,10 +1011,26 @@ mod jiff_0_1 {
                 self.hour(),
                 self.minute(),
                 self.second(),
-                i32::try_from(ms).ok()?,
+                i32::try_from(ms.checked_mul(1_000)?).ok()?,
             )
             .ok()
         }
+
+        fn hour(&self) -> u8 {
+            jiff::civil::Time::hour(*self) as u8
+        }
+
+        fn minute(&self) -> u8 {
+            jiff::civil::Time::minute(*self) as u8
+        }
+
+        fn second(&self) -> u8 {
+            jiff::civil::Time::second(*self) as u8
+        }
+
+        fn microsecond(&self) -> u32 {
+            (jiff::civil::Time::subsec_nanosecond(*self) / 1_000) as u32
+        }
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_1")))]
@@ -269,23 +1039,166 @@ mod jiff_0_1 {
         type Date = jiff::civil::Date;
         type Time = jiff::civil::Time;
 
-        fn new(tz: Self::TimeZone, date: Self::Date, time: Self::Time) -> Self {
-            tz.to_ambiguous_zoned(date.to_datetime(time))
-                .compatible()
-                .unwrap()
+        fn new(
+            tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            disambiguation: Disambiguation,
+        ) -> DateResult<Self> {
+            let zoned = tz.to_ambiguous_zoned(date.to_datetime(time));
+            let zoned = match disambiguation {
+                Disambiguation::Compatible => zoned.compatible(),
+                Disambiguation::Earlier => zoned.earlier(),
+                Disambiguation::Later => zoned.later(),
+                Disambiguation::Reject => match zoned.offset() {
+                    jiff::tz::AmbiguousOffset::Unambiguous { .. } => zoned.compatible(),
+                    jiff::tz::AmbiguousOffset::Gap { .. } => {
+                        return Err(DateError::NonexistentTime)
+                    }
+                    jiff::tz::AmbiguousOffset::Fold { .. } => return Err(DateError::AmbiguousTime),
+                },
+            };
+            zoned.map_err(|_| DateError::MissingDate)
         }
 
-        fn split(self) -> (Self::TimeZone, Self::Date, Self::Time) {
-            (self.time_zone().clone(), self.date(), self.time())
+        fn split(self) -> Option<(Self::TimeZone, Self::Date, Self::Time)> {
+            Some((self.time_zone().clone(), self.date(), self.time()))
         }
 
-        fn with_offset(self, secs: i64) -> Option<Self> {
-            let offset = self.time_zone().to_offset(self.timestamp()).0.seconds() as i64;
-            self.offset_seconds(offset - secs)
+        fn from_offset(
+            tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            offset: i64,
+        ) -> DateResult<Self> {
+            let offset = i32::try_from(offset)
+                .ok()
+                .and_then(|offset| jiff::tz::Offset::from_seconds(offset).ok())
+                .ok_or(DateError::UnsupportedTimeZone)?;
+            let timestamp = offset
+                .to_timestamp(date.to_datetime(time))
+                .map_err(|_| DateError::MissingDate)?;
+            Ok(timestamp.to_zoned(tz))
         }
 
         fn offset_seconds(self, secs: i64) -> Option<Self> {
-            self.checked_add(jiff::Span::new().seconds(secs)).ok()
+            self.checked_add(jiff::Span::new().try_seconds(secs).ok()?)
+                .ok()
+        }
+
+        fn to_zone(self, zone: &Zone) -> Option<Self> {
+            let tz = if let Some(Ok(tz)) = zone.iana_name().map(jiff::tz::TimeZone::get) {
+                tz
+            } else {
+                // fallback to the offset in effect at this instant
+                let offset = zone.offset_at(self.timestamp().as_second())?;
+                let offset = jiff::tz::Offset::from_seconds(i32::try_from(offset).ok()?).ok()?;
+                jiff::tz::TimeZone::fixed(offset)
+            };
+            Some(self.with_time_zone(tz))
+        }
+
+        fn utc_offset(&self) -> Option<i64> {
+            Some(jiff::Zoned::offset(self).seconds().into())
+        }
+    }
+
+    /// A date and time without a timezone.
+    ///
+    /// Inputs with a timezone, an offset or a unix timestamp describe an instant,
+    /// which can't be represented, so they give [`DateError::UnsupportedTimeZone`].
+    #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_1")))]
+    impl DateTime for jiff::civil::DateTime {
+        type TimeZone = ();
+        type Date = jiff::civil::Date;
+        type Time = jiff::civil::Time;
+
+        fn new(
+            _tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            _disambiguation: Disambiguation,
+        ) -> DateResult<Self> {
+            Ok(date.to_datetime(time))
+        }
+
+        fn split(self) -> Option<(Self::TimeZone, Self::Date, Self::Time)> {
+            Some(((), self.date(), self.time()))
+        }
+
+        fn from_offset(
+            _tz: Self::TimeZone,
+            _date: Self::Date,
+            _time: Self::Time,
+            _offset: i64,
+        ) -> DateResult<Self> {
+            Err(DateError::UnsupportedTimeZone)
+        }
+
+        fn offset_seconds(self, secs: i64) -> Option<Self> {
+            self.checked_add(jiff::Span::new().try_seconds(secs).ok()?)
+                .ok()
+        }
+
+        fn to_zone(self, _zone: &Zone) -> Option<Self> {
+            None
+        }
+
+        fn utc_offset(&self) -> Option<i64> {
+            None
+        }
+    }
+
+    /// An instant, with calendar operations in UTC
+    #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_1")))]
+    impl DateTime for jiff::Timestamp {
+        type TimeZone = ();
+        type Date = jiff::civil::Date;
+        type Time = jiff::civil::Time;
+
+        fn new(
+            _tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            _disambiguation: Disambiguation,
+        ) -> DateResult<Self> {
+            jiff::tz::Offset::UTC
+                .to_timestamp(date.to_datetime(time))
+                .map_err(|_| DateError::MissingDate)
+        }
+
+        fn split(self) -> Option<(Self::TimeZone, Self::Date, Self::Time)> {
+            let datetime = jiff::tz::Offset::UTC.to_datetime(self);
+            Some(((), datetime.date(), datetime.time()))
+        }
+
+        fn from_offset(
+            _tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            offset: i64,
+        ) -> DateResult<Self> {
+            let offset = i32::try_from(offset)
+                .ok()
+                .and_then(|offset| jiff::tz::Offset::from_seconds(offset).ok())
+                .ok_or(DateError::UnsupportedTimeZone)?;
+            offset
+                .to_timestamp(date.to_datetime(time))
+                .map_err(|_| DateError::MissingDate)
+        }
+
+        fn offset_seconds(self, secs: i64) -> Option<Self> {
+            self.checked_add(jiff::Span::new().try_seconds(secs).ok()?)
+                .ok()
+        }
+
+        fn to_zone(self, _zone: &Zone) -> Option<Self> {
+            // the instant is the same in every timezone
+            Some(self)
+        }
+
+        fn utc_offset(&self) -> Option<i64> {
+            Some(0)
         }
     }
 }
@@ -295,11 +1208,14 @@ mod jiff_0_2 {
     use jiff::Span;
     use jiff_0_2 as jiff;
 
-    use super::{Date, DateTime, Time};
+    use super::{Date, DateTime, Time, Zone};
+    use crate::{DateError, DateResult, Disambiguation};
 
-    impl super::sealed::Sealed for jiff::civil::Date {}
-    impl super::sealed::Sealed for jiff::civil::Time {}
-    impl super::sealed::Sealed for jiff::Zoned {}
+    impl super::sealed::Date for jiff::civil::Date {}
+    impl super::sealed::Time for jiff::civil::Time {}
+    impl super::sealed::DateTime for jiff::Zoned {}
+    impl super::sealed::DateTime for jiff::civil::DateTime {}
+    impl super::sealed::DateTime for jiff::Timestamp {}
 
     #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_2")))]
     impl Date for jiff::civil::Date {
@@ -308,17 +1224,25 @@ mod jiff_0_2 {
         }
 
         fn offset_months(self, months: i32) -> Option<Self> {
-            self.checked_add(Span::new().months(months)).ok()
+            self.checked_add(Span::new().try_months(months).ok()?).ok()
         }
 
         fn offset_days(self, days: i64) -> Option<Self> {
-            self.checked_add(Span::new().days(days)).ok()
+            self.checked_add(Span::new().try_days(days).ok()?).ok()
         }
 
         fn year(&self) -> i32 {
             jiff::civil::Date::year(*self) as i32
         }
 
+        fn month(&self) -> u8 {
+            jiff::civil::Date::month(*self) as u8
+        }
+
+        fn day(&self) -> u8 {
+            jiff::civil::Date::day(*self) as u8
+        }
+
         fn weekday(&self) -> u8 {
             jiff::civil::Date::weekday(*self).to_monday_zero_offset() as u8
         }
@@ -341,10 +1265,26 @@ mod jiff_0_2 {
                 self.hour(),
                 self.minute(),
                 self.second(),
-                i32::try_from(ms).ok()?,
+                i32::try_from(ms.checked_mul(1_000)?).ok()?,
             )
             .ok()
         }
+
+        fn hour(&self) -> u8 {
+            jiff::civil::Time::hour(*self) as u8
+        }
+
+        fn minute(&self) -> u8 {
+            jiff::civil::Time::minute(*self) as u8
+        }
+
+        fn second(&self) -> u8 {
+            jiff::civil::Time::second(*self) as u8
+        }
+
+        fn microsecond(&self) -> u32 {
+            (jiff::civil::Time::subsec_nanosecond(*self) / 1_000) as u32
+        }
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_2")))]
@@ -353,23 +1293,169 @@ mod jiff_0_2 {
         type Date = jiff::civil::Date;
         type Time = jiff::civil::Time;
 
-        fn new(tz: Self::TimeZone, date: Self::Date, time: Self::Time) -> Self {
-            tz.to_ambiguous_zoned(date.to_datetime(time))
-                .compatible()
-                .unwrap()
+        fn new(
+            tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            disambiguation: Disambiguation,
+        ) -> DateResult<Self> {
+            let zoned = tz.to_ambiguous_zoned(date.to_datetime(time));
+            let zoned = match disambiguation {
+                Disambiguation::Compatible => zoned.compatible(),
+                Disambiguation::Earlier => zoned.earlier(),
+                Disambiguation::Later => zoned.later(),
+                Disambiguation::Reject => match zoned.offset() {
+                    jiff::tz::AmbiguousOffset::Unambiguous { .. } => zoned.compatible(),
+                    jiff::tz::AmbiguousOffset::Gap { .. } => {
+                        return Err(DateError::NonexistentTime)
+                    }
+                    jiff::tz::AmbiguousOffset::Fold { .. } => return Err(DateError::AmbiguousTime),
+                },
+            };
+            zoned.map_err(|_| DateError::MissingDate)
         }
 
-        fn split(self) -> (Self::TimeZone, Self::Date, Self::Time) {
-            (self.time_zone().clone(), self.date(), self.time())
+        fn split(self) -> Option<(Self::TimeZone, Self::Date, Self::Time)> {
+            Some((self.time_zone().clone(), self.date(), self.time()))
+        }
+
+        fn from_offset(
+            tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            offset: i64,
+        ) -> DateResult<Self> {
+            let offset = i32::try_from(offset)
+                .ok()
+                .and_then(|offset| jiff::tz::Offset::from_seconds(offset).ok())
+                .ok_or(DateError::UnsupportedTimeZone)?;
+            let timestamp = offset
+                .to_timestamp(date.to_datetime(time))
+                .map_err(|_| DateError::MissingDate)?;
+            Ok(timestamp.to_zoned(tz))
+        }
+
+        fn offset_seconds(self, secs: i64) -> Option<Self> {
+            self.checked_add(jiff::Span::new().try_seconds(secs).ok()?)
+                .ok()
+        }
+
+        fn to_zone(self, zone: &Zone) -> Option<Self> {
+            if let Zone::Jiff(tz) = zone {
+                return Some(self.with_time_zone(tz.clone()));
+            }
+            let tz = if let Some(Ok(tz)) = zone.iana_name().map(jiff::tz::TimeZone::get) {
+                tz
+            } else {
+                // fallback to the offset in effect at this instant
+                let offset = zone.offset_at(self.timestamp().as_second())?;
+                let offset = jiff::tz::Offset::from_seconds(i32::try_from(offset).ok()?).ok()?;
+                jiff::tz::TimeZone::fixed(offset)
+            };
+            Some(self.with_time_zone(tz))
+        }
+
+        fn utc_offset(&self) -> Option<i64> {
+            Some(jiff::Zoned::offset(self).seconds().into())
+        }
+    }
+
+    /// A date and time without a timezone.
+    ///
+    /// Inputs with a timezone, an offset or a unix timestamp describe an instant,
+    /// which can't be represented, so they give [`DateError::UnsupportedTimeZone`].
+    #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_2")))]
+    impl DateTime for jiff::civil::DateTime {
+        type TimeZone = ();
+        type Date = jiff::civil::Date;
+        type Time = jiff::civil::Time;
+
+        fn new(
+            _tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            _disambiguation: Disambiguation,
+        ) -> DateResult<Self> {
+            Ok(date.to_datetime(time))
+        }
+
+        fn split(self) -> Option<(Self::TimeZone, Self::Date, Self::Time)> {
+            Some(((), self.date(), self.time()))
         }
 
-        fn with_offset(self, secs: i64) -> Option<Self> {
-            let offset = self.time_zone().to_offset(self.timestamp()).seconds() as i64;
-            self.offset_seconds(offset - secs)
+        fn from_offset(
+            _tz: Self::TimeZone,
+            _date: Self::Date,
+            _time: Self::Time,
+            _offset: i64,
+        ) -> DateResult<Self> {
+            Err(DateError::UnsupportedTimeZone)
         }
 
         fn offset_seconds(self, secs: i64) -> Option<Self> {
-            self.checked_add(jiff::Span::new().seconds(secs)).ok()
+            self.checked_add(jiff::Span::new().try_seconds(secs).ok()?)
+                .ok()
+        }
+
+        fn to_zone(self, _zone: &Zone) -> Option<Self> {
+            None
+        }
+
+        fn utc_offset(&self) -> Option<i64> {
+            None
+        }
+    }
+
+    /// An instant, with calendar operations in UTC
+    #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_2")))]
+    impl DateTime for jiff::Timestamp {
+        type TimeZone = ();
+        type Date = jiff::civil::Date;
+        type Time = jiff::civil::Time;
+
+        fn new(
+            _tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            _disambiguation: Disambiguation,
+        ) -> DateResult<Self> {
+            jiff::tz::Offset::UTC
+                .to_timestamp(date.to_datetime(time))
+                .map_err(|_| DateError::MissingDate)
+        }
+
+        fn split(self) -> Option<(Self::TimeZone, Self::Date, Self::Time)> {
+            let datetime = jiff::tz::Offset::UTC.to_datetime(self);
+            Some(((), datetime.date(), datetime.time()))
+        }
+
+        fn from_offset(
+            _tz: Self::TimeZone,
+            date: Self::Date,
+            time: Self::Time,
+            offset: i64,
+        ) -> DateResult<Self> {
+            let offset = i32::try_from(offset)
+                .ok()
+                .and_then(|offset| jiff::tz::Offset::from_seconds(offset).ok())
+                .ok_or(DateError::UnsupportedTimeZone)?;
+            offset
+                .to_timestamp(date.to_datetime(time))
+                .map_err(|_| DateError::MissingDate)
+        }
+
+        fn offset_seconds(self, secs: i64) -> Option<Self> {
+            self.checked_add(jiff::Span::new().try_seconds(secs).ok()?)
+                .ok()
+        }
+
+        fn to_zone(self, _zone: &Zone) -> Option<Self> {
+            // the instant is the same in every timezone
+            Some(self)
+        }
+
+        fn utc_offset(&self) -> Option<i64> {
+            Some(0)
         }
     }
 }
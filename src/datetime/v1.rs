@@ -0,0 +1,272 @@
+//! Traits for using your own date-time types with interim.
+//!
+//! The traits in [`datetime`](super) are sealed, and change as interim needs.
+//! These traits are stable: a breaking change to them comes as a new `v2` module,
+//! alongside this one. Types that implement them implement the internal traits too,
+//! so they work with [`parse_date_string`](crate::parse_date_string) and friends.
+//!
+//! [`conformance::Harness`](crate::conformance::Harness) runs interim's own test suite
+//! against an implementation.
+//!
+//! ```
+//! use interim::datetime::v1;
+//! use interim::{civil, parse_date_string, DateResult, Dialect, Disambiguation};
+//!
+//! /// Seconds since the unix epoch, in UTC
+//! #[derive(Clone, Copy, Debug, PartialEq)]
+//! struct Seconds(i64);
+//!
+//! impl v1::DateTime for Seconds {
+//!     type TimeZone = ();
+//!     type Date = civil::Date;
+//!     type Time = civil::Time;
+//!
+//!     fn from_local(
+//!         (): (),
+//!         date: civil::Date,
+//!         time: civil::Time,
+//!         _: Disambiguation,
+//!     ) -> DateResult<Self> {
+//!         Self::from_offset((), date, time, 0)
+//!     }
+//!
+//!     fn from_offset(
+//!         (): (),
+//!         date: civil::Date,
+//!         time: civil::Time,
+//!         offset: i64,
+//!     ) -> DateResult<Self> {
+//!         let utc = civil::DateTime::from_parts(date, time, 0).unwrap();
+//!         Ok(Self(utc.unix_timestamp() - offset))
+//!     }
+//!
+//!     fn split(self) -> ((), civil::Date, civil::Time) {
+//!         let utc = civil::DateTime::from_unix(self.0, 0, 0).unwrap();
+//!         ((), utc.date(), utc.time())
+//!     }
+//!
+//!     fn offset_seconds(self, secs: i64) -> Option<Self> {
+//!         self.0.checked_add(secs).map(Self)
+//!     }
+//!
+//!     fn utc_offset(&self) -> Option<i64> {
+//!         Some(0)
+//!     }
+//! }
+//!
+//! let now = Seconds(1_521_630_000); // 2018-03-21T11:00:00Z
+//! let date = parse_date_string("tomorrow 9am", now, Dialect::Uk).unwrap();
+//! assert_eq!(date, Seconds(1_521_709_200));
+//! ```
+
+use crate::{DateResult, Disambiguation};
+
+/// A calendar date
+pub trait Date: Clone + PartialOrd {
+    /// The date with the given year, month (1-12) and day of the month (1-31), if it exists
+    fn from_ymd(year: i32, month: u8, day: u8) -> Option<Self>;
+    /// The date a number of calendar months later (or earlier, if negative).
+    ///
+    /// The day of the month is clamped to the last day of the new month,
+    /// so a month after January 31 is the last day of February.
+    fn offset_months(self, months: i32) -> Option<Self>;
+    /// The date a number of days later (or earlier, if negative)
+    fn offset_days(self, days: i64) -> Option<Self>;
+
+    /// The year in the proleptic Gregorian calendar, with 0 for 1 BC and negative years before
+    /// it, within whatever range the type supports
+    fn year(&self) -> i32;
+    /// The month, from 1 for January
+    fn month(&self) -> u8;
+    /// The day of the month, from 1
+    fn day(&self) -> u8;
+    /// The day of the week, counting from 0 for Monday
+    fn weekday(&self) -> u8;
+}
+
+/// A time of day
+pub trait Time: Clone + PartialOrd {
+    /// The time with the given hour (0-23), minute (0-59) and second (0-59), if it is valid
+    fn from_hms(h: u32, m: u32, s: u32) -> Option<Self>;
+    /// The same time with the given microsecond (0-999999).
+    /// Types with less precision may truncate it.
+    fn with_micros(self, ms: u32) -> Option<Self>;
+
+    /// The hour, from 0 to 23
+    fn hour(&self) -> u8;
+    /// The minute, from 0 to 59
+    fn minute(&self) -> u8;
+    /// The second, from 0 to 59
+    fn second(&self) -> u8;
+    /// The microsecond, from 0 to 999999
+    fn microsecond(&self) -> u32;
+}
+
+/// A date and time, with any timezone information the type carries
+pub trait DateTime: Sized {
+    /// The part of the type kept when the date and time change, like the timezone.
+    /// Types without a timezone use `()`.
+    type TimeZone;
+    /// The type of the local date
+    type Date: Date;
+    /// The type of the local time of day
+    type Time: Time;
+
+    /// The date and time in `tz`.
+    ///
+    /// When a DST transition skips or repeats the local time, it's resolved
+    /// with `disambiguation`.
+    fn from_local(
+        tz: Self::TimeZone,
+        date: Self::Date,
+        time: Self::Time,
+        disambiguation: Disambiguation,
+    ) -> DateResult<Self>;
+    /// The instant given by the date and time at `offset` seconds east of UTC, kept in `tz`.
+    ///
+    /// Types that can't represent an exact instant should give
+    /// [`DateError::UnsupportedTimeZone`](crate::DateError::UnsupportedTimeZone).
+    fn from_offset(
+        tz: Self::TimeZone,
+        date: Self::Date,
+        time: Self::Time,
+        offset: i64,
+    ) -> DateResult<Self>;
+    /// The local date and time, and the timezone
+    fn split(self) -> (Self::TimeZone, Self::Date, Self::Time);
+    /// The date-time a number of seconds later (or earlier, if negative)
+    fn offset_seconds(self, secs: i64) -> Option<Self>;
+    /// The same instant in another timezone, for inputs like "3pm UTC in Europe/London".
+    ///
+    /// Types that can't change zone give `None`, which is the default.
+    fn to_zone(self, zone: Zone<'_>) -> Option<Self> {
+        let _ = zone;
+        None
+    }
+    /// The offset from UTC of the local date and time, in seconds east.
+    ///
+    /// Types without a timezone give `None`, which is the default, and the time between two
+    /// of them is measured on the clock. Types with a timezone should give their offset,
+    /// or the time across a DST change is off by the change.
+    fn utc_offset(&self) -> Option<i64> {
+        None
+    }
+}
+
+/// A timezone to convert to
+#[derive(Clone, Copy, Debug)]
+pub struct Zone<'a>(&'a crate::tz::Zone);
+
+impl Zone<'_> {
+    /// The IANA name of this zone, like "Europe/London", if it has one.
+    /// Fixed offsets have no name.
+    #[must_use]
+    pub fn iana_name(&self) -> Option<&str> {
+        self.0.iana_name()
+    }
+
+    /// The offset from UTC in seconds, in effect at the instant given as seconds since the unix epoch
+    #[must_use]
+    pub fn offset_at(&self, timestamp: i64) -> Option<i64> {
+        self.0.offset_at(timestamp)
+    }
+}
+
+impl<T: Date> super::sealed::Date for T {}
+impl<T: Time> super::sealed::Time for T {}
+impl<T: DateTime> super::sealed::DateTime for T {}
+
+impl<T: Date> super::Date for T {
+    fn from_ymd(year: i32, month: u8, day: u8) -> Option<Self> {
+        <T as Date>::from_ymd(year, month, day)
+    }
+
+    fn offset_months(self, months: i32) -> Option<Self> {
+        <T as Date>::offset_months(self, months)
+    }
+
+    fn offset_days(self, days: i64) -> Option<Self> {
+        <T as Date>::offset_days(self, days)
+    }
+
+    fn year(&self) -> i32 {
+        <T as Date>::year(self)
+    }
+
+    fn month(&self) -> u8 {
+        <T as Date>::month(self)
+    }
+
+    fn day(&self) -> u8 {
+        <T as Date>::day(self)
+    }
+
+    fn weekday(&self) -> u8 {
+        <T as Date>::weekday(self)
+    }
+}
+
+impl<T: Time> super::Time for T {
+    fn from_hms(h: u32, m: u32, s: u32) -> Option<Self> {
+        <T as Time>::from_hms(h, m, s)
+    }
+
+    fn with_micros(self, ms: u32) -> Option<Self> {
+        <T as Time>::with_micros(self, ms)
+    }
+
+    fn hour(&self) -> u8 {
+        <T as Time>::hour(self)
+    }
+
+    fn minute(&self) -> u8 {
+        <T as Time>::minute(self)
+    }
+
+    fn second(&self) -> u8 {
+        <T as Time>::second(self)
+    }
+
+    fn microsecond(&self) -> u32 {
+        <T as Time>::microsecond(self)
+    }
+}
+
+impl<T: DateTime> super::DateTime for T {
+    type TimeZone = T::TimeZone;
+    type Date = T::Date;
+    type Time = T::Time;
+
+    fn new(
+        tz: Self::TimeZone,
+        date: Self::Date,
+        time: Self::Time,
+        disambiguation: Disambiguation,
+    ) -> DateResult<Self> {
+        T::from_local(tz, date, time, disambiguation)
+    }
+
+    fn split(self) -> Option<(Self::TimeZone, Self::Date, Self::Time)> {
+        Some(<T as DateTime>::split(self))
+    }
+
+    fn from_offset(
+        tz: Self::TimeZone,
+        date: Self::Date,
+        time: Self::Time,
+        offset: i64,
+    ) -> DateResult<Self> {
+        <T as DateTime>::from_offset(tz, date, time, offset)
+    }
+
+    fn offset_seconds(self, secs: i64) -> Option<Self> {
+        <T as DateTime>::offset_seconds(self, secs)
+    }
+
+    fn to_zone(self, zone: &crate::tz::Zone) -> Option<Self> {
+        <T as DateTime>::to_zone(self, Zone(zone))
+    }
+    fn utc_offset(&self) -> Option<i64> {
+        <T as DateTime>::utc_offset(self)
+    }
+}
@@ -0,0 +1,474 @@
+use core::cmp::Ordering;
+use core::fmt;
+
+use crate::datetime::{Date, DateTime, Time};
+use crate::{civil, DateError, DateResult, Disambiguation, Interval};
+
+/// A unit of time, for the largest and smallest units of a [`Difference`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// How a [`Difference`] is rounded to its smallest unit
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Towards zero, so 1 hour 59 minutes is 1 hour
+    #[default]
+    Trunc,
+    /// Away from zero, so 1 hour 1 minute is 2 hours
+    Expand,
+    /// To the nearest unit, and away from zero when it's halfway,
+    /// so 1 hour 30 minutes is 2 hours
+    HalfExpand,
+}
+
+/// Options for [`difference_with`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DifferenceOptions {
+    largest: Unit,
+    smallest: Unit,
+    rounding: Rounding,
+}
+
+impl DifferenceOptions {
+    /// Years down to seconds, truncating fractions of a second
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            largest: Unit::Year,
+            smallest: Unit::Second,
+            rounding: Rounding::Trunc,
+        }
+    }
+
+    /// The largest unit to use. Weeks are only used when this is [`Unit::Week`], so the
+    /// default of [`Unit::Year`] gives years, months and days. A largest unit smaller than
+    /// the smallest unit is raised to it.
+    #[must_use]
+    pub const fn largest(mut self, unit: Unit) -> Self {
+        self.largest = unit;
+        self
+    }
+
+    /// The smallest unit to use, with anything shorter rounded. Defaults to [`Unit::Second`].
+    #[must_use]
+    pub const fn smallest(mut self, unit: Unit) -> Self {
+        self.smallest = unit;
+        self
+    }
+
+    /// How to round to the smallest unit. Defaults to [`Rounding::Trunc`].
+    #[must_use]
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+}
+
+impl Default for DifferenceOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The difference between two date-times in calendar units, from [`difference`].
+///
+/// All the units have the same sign. Adding the years and months to the start, then the weeks
+/// and days, then the rest as an exact number of seconds, gives the end, in the same way as
+/// [`Interval::add_to`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Difference {
+    years: i64,
+    months: i64,
+    weeks: i64,
+    days: i64,
+    hours: i64,
+    minutes: i64,
+    seconds: i64,
+}
+
+impl Difference {
+    #[must_use]
+    pub const fn years(&self) -> i64 {
+        self.years
+    }
+
+    #[must_use]
+    pub const fn months(&self) -> i64 {
+        self.months
+    }
+
+    #[must_use]
+    pub const fn weeks(&self) -> i64 {
+        self.weeks
+    }
+
+    #[must_use]
+    pub const fn days(&self) -> i64 {
+        self.days
+    }
+
+    #[must_use]
+    pub const fn hours(&self) -> i64 {
+        self.hours
+    }
+
+    #[must_use]
+    pub const fn minutes(&self) -> i64 {
+        self.minutes
+    }
+
+    #[must_use]
+    pub const fn seconds(&self) -> i64 {
+        self.seconds
+    }
+
+    /// The difference as intervals of months, days and seconds, which give the end
+    /// when they're added to the start in that order
+    #[must_use]
+    pub const fn intervals(&self) -> [Interval; 3] {
+        [
+            Interval::Months(self.years * 12 + self.months),
+            Interval::Days(self.weeks * 7 + self.days),
+            Interval::Seconds(self.hours * 3600 + self.minutes * 60 + self.seconds),
+        ]
+    }
+}
+
+/// Writes each unit that isn't zero, like "1 year 2 months 3 days 4 hours", and "3 days ago" if
+/// it's negative. The alternate form `{:#}` is compact, like "1y2mo3d4h" or "-3d".
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let units = [
+            (self.years, "year", "y"),
+            (self.months, "month", "mo"),
+            (self.weeks, "week", "w"),
+            (self.days, "day", "d"),
+            (self.hours, "hour", "h"),
+            (self.minutes, "minute", "m"),
+            (self.seconds, "second", "s"),
+        ];
+        let negative = units.iter().any(|&(n, _, _)| n < 0);
+
+        if f.alternate() && negative {
+            f.write_str("-")?;
+        }
+        let mut first = true;
+        for (i, &(n, name, short)) in units.iter().enumerate() {
+            // zero is written in the smallest unit, "0 seconds"
+            if n == 0 && !(first && i == units.len() - 1) {
+                continue;
+            }
+            let count = n.unsigned_abs();
+            if f.alternate() {
+                write!(f, "{count}{short}")?;
+            } else {
+                let space = if first { "" } else { " " };
+                let plural = if count == 1 { "" } else { "s" };
+                write!(f, "{space}{count} {name}{plural}")?;
+            }
+            first = false;
+        }
+        if !f.alternate() && negative {
+            f.write_str(" ago")?;
+        }
+        Ok(())
+    }
+}
+
+/// The difference from `start` to `end` in years, months, days, hours, minutes and seconds,
+/// with fractions of a second left out. It's negative if `end` is before `start`.
+///
+/// Months are counted first, keeping the day of the month where it exists, then days, keeping
+/// the time of day, then the time that's left. This is how [`Interval::add_to`] adds them, so
+/// 31 January to 29 February is 1 month, and a day across a change to daylight saving time is
+/// still 1 day. The time that's left is measured between the instants, so `start` and `end` can
+/// be at different offsets, and the hour repeated when daylight saving time ends counts.
+/// Types without a timezone are measured on the clock.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use interim::difference;
+///
+/// let start = Utc.with_ymd_and_hms(2024, 1, 31, 9, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2025, 3, 1, 17, 30, 0).unwrap();
+/// let difference = difference(&start, &end).unwrap();
+/// assert_eq!(difference.to_string(), "1 year 1 month 1 day 8 hours 30 minutes");
+/// ```
+///
+/// # Errors
+///
+/// [`DateError::MissingDate`] if an intermediate date-time is out of range for `Dt`, and
+/// [`DateError::UnsupportedTimeZone`] if only one of `start` and `end` has an offset from UTC.
+pub fn difference<Dt: DateTime + Clone>(start: &Dt, end: &Dt) -> DateResult<Difference> {
+    difference_with(start, end, DifferenceOptions::new())
+}
+
+/// The difference from `start` to `end`, like [`difference`], in the range of units and with
+/// the rounding given by the options.
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use interim::{difference_with, DifferenceOptions, Rounding, Unit};
+///
+/// let start = Utc.with_ymd_and_hms(2024, 1, 1, 9, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2024, 1, 18, 21, 0, 0).unwrap();
+/// let options = DifferenceOptions::new()
+///     .largest(Unit::Week)
+///     .smallest(Unit::Day)
+///     .rounding(Rounding::HalfExpand);
+/// assert_eq!(difference_with(&start, &end, options).unwrap().to_string(), "2 weeks 4 days");
+/// ```
+///
+/// # Errors
+///
+/// Like [`difference`].
+pub fn difference_with<Dt: DateTime + Clone>(
+    start: &Dt,
+    end: &Dt,
+    options: DifferenceOptions,
+) -> DateResult<Difference> {
+    let largest = options.largest.max(options.smallest);
+    let exact = Amounts::between(start, end, largest)?;
+    let (lower, step) = exact.truncate(options.smallest);
+    if lower == exact {
+        return Ok(exact.balance(largest));
+    }
+
+    // how far the end is between the smallest units either side of it
+    let low = lower.add_to(start.clone())?;
+    let high = lower.plus(step).add_to(start.clone())?;
+    let up = match options.rounding {
+        Rounding::Trunc => false,
+        Rounding::Expand => true,
+        Rounding::HalfExpand => {
+            let rest = elapsed(&low, end)?.unsigned_abs();
+            let size = elapsed(&low, &high)?.unsigned_abs();
+            rest * 2 >= size
+        }
+    };
+    if up {
+        // carrying into larger units depends on the calendar, so measure it again
+        Ok(Amounts::between(start, &high, largest)?.balance(largest))
+    } else {
+        Ok(lower.balance(largest))
+    }
+}
+
+// months, days and seconds, in the order they're added
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Amounts {
+    months: i64,
+    days: i64,
+    seconds: i64,
+}
+
+impl Amounts {
+    fn between<Dt: DateTime + Clone>(start: &Dt, end: &Dt, largest: Unit) -> DateResult<Self> {
+        let sign = match micros_between(start, end)?.cmp(&0) {
+            Ordering::Greater => 1,
+            Ordering::Less => -1,
+            Ordering::Equal => return Ok(Self::default()),
+        };
+        // whether adding the amounts to the start goes past the end
+        let past = |amounts: Self| -> DateResult<bool> {
+            let rest = micros_between(&amounts.add_to(start.clone())?, end)?;
+            Ok(rest * i128::from(sign) < 0)
+        };
+        // the most whole units that don't go past the end, from a guess on the wall clock,
+        // which is off by one when the end is at a different offset
+        let settle = |mut amounts: Self, unit: fn(i64) -> Self| -> DateResult<Self> {
+            while past(amounts)? {
+                amounts = amounts.plus(unit(-sign));
+            }
+            while !past(amounts.plus(unit(sign)))? {
+                amounts = amounts.plus(unit(sign));
+            }
+            Ok(amounts)
+        };
+
+        let mut amounts = Self::default();
+        let (start_date, _) = local(start)?;
+        let (end_date, _) = local(end)?;
+        if largest >= Unit::Month {
+            amounts.months = month_number(&end_date) - month_number(&start_date);
+            // the day of the month or the time of day may not have been reached yet
+            amounts = settle(amounts, |months| Self {
+                months,
+                ..Self::default()
+            })?;
+        }
+        if largest >= Unit::Day {
+            let (date, _) = local(
+                &Interval::Months(amounts.months)
+                    .offset_from(start.clone(), Disambiguation::Compatible)?,
+            )?;
+            amounts.days = day_number(&end_date)? - day_number(&date)?;
+            amounts = settle(amounts, |days| Self {
+                days,
+                ..Self::default()
+            })?;
+        }
+        amounts.seconds = elapsed(&amounts.add_to(start.clone())?, end)?;
+        Ok(amounts)
+    }
+
+    fn add_to<Dt: DateTime>(self, base: Dt) -> DateResult<Dt> {
+        [
+            Interval::Months(self.months),
+            Interval::Days(self.days),
+            Interval::Seconds(self.seconds),
+        ]
+        .into_iter()
+        // adding nothing keeps the offset, in the repeated hour when the clocks go back
+        .filter(|interval| {
+            !matches!(
+                interval,
+                Interval::Months(0) | Interval::Days(0) | Interval::Seconds(0)
+            )
+        })
+        .try_fold(base, |base, interval| {
+            interval.offset_from(base, Disambiguation::Compatible)
+        })
+    }
+
+    fn plus(self, other: Self) -> Self {
+        Self {
+            months: self.months + other.months,
+            days: self.days + other.days,
+            seconds: self.seconds + other.seconds,
+        }
+    }
+
+    // the amounts without anything smaller than the unit, and the unit in the same direction
+    fn truncate(self, smallest: Unit) -> (Self, Self) {
+        let sign = if self.months < 0 || self.days < 0 || self.seconds < 0 {
+            -1
+        } else {
+            1
+        };
+        let (months, days, seconds) = match smallest {
+            Unit::Year => (12, 0, 0),
+            Unit::Month => (1, 0, 0),
+            Unit::Week => (0, 7, 0),
+            Unit::Day => (0, 1, 0),
+            Unit::Hour => (0, 0, 3600),
+            Unit::Minute => (0, 0, 60),
+            Unit::Second => (0, 0, 1),
+        };
+        let lower = Self {
+            months: if months == 0 {
+                self.months
+            } else {
+                self.months / months * months
+            },
+            days: match (months, days) {
+                (0, 0) => self.days,
+                (0, days) => self.days / days * days,
+                _ => 0,
+            },
+            seconds: if seconds == 0 {
+                0
+            } else {
+                self.seconds / seconds * seconds
+            },
+        };
+        let step = Self {
+            months: months * sign,
+            days: days * sign,
+            seconds: seconds * sign,
+        };
+        (lower, step)
+    }
+
+    fn balance(self, largest: Unit) -> Difference {
+        let (years, months) = if largest == Unit::Year {
+            (self.months / 12, self.months % 12)
+        } else {
+            (0, self.months)
+        };
+        let (weeks, days) = if largest == Unit::Week {
+            (self.days / 7, self.days % 7)
+        } else {
+            (0, self.days)
+        };
+        let (hours, rest) = if largest >= Unit::Hour {
+            (self.seconds / 3600, self.seconds % 3600)
+        } else {
+            (0, self.seconds)
+        };
+        let (minutes, seconds) = if largest >= Unit::Minute {
+            (rest / 60, rest % 60)
+        } else {
+            (0, rest)
+        };
+        Difference {
+            years,
+            months,
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+        }
+    }
+}
+
+// the exact number of seconds from `start` to `end`, without any fraction of a second
+pub(crate) fn elapsed<Dt: DateTime + Clone>(start: &Dt, end: &Dt) -> DateResult<i64> {
+    i64::try_from(micros_between(start, end)? / 1_000_000).map_err(|_| DateError::MissingDate)
+}
+
+// `dt` on the clock of `other`, without any fraction of a second
+pub(crate) fn on_clock_of<Dt: DateTime + Clone>(dt: &Dt, other: &Dt) -> DateResult<Dt> {
+    let (_, time) = local(other)?;
+    // the whole seconds from the start of the second `other` is in
+    let micros = micros_between(other, dt)? + i128::from(time.microsecond());
+    let secs = i64::try_from(micros.div_euclid(1_000_000)).map_err(|_| DateError::MissingDate)?;
+    other
+        .clone()
+        .offset_seconds(secs)
+        .ok_or(DateError::MissingDate)
+}
+
+// the exact number of microseconds from `start` to `end`, which are measured on the clock
+// for types without a timezone
+fn micros_between<Dt: DateTime + Clone>(start: &Dt, end: &Dt) -> DateResult<i128> {
+    let micros = |dt: &Dt, offset: i64| -> DateResult<i128> {
+        let (date, time) = local(dt)?;
+        let secs = i128::from(day_number(&date)?) * 86400
+            + i128::from(time.hour()) * 3600
+            + i128::from(time.minute()) * 60
+            + i128::from(time.second())
+            - i128::from(offset);
+        Ok(secs * 1_000_000 + i128::from(time.microsecond()))
+    };
+    match (start.utc_offset(), end.utc_offset()) {
+        (Some(from), Some(to)) => Ok(micros(end, to)? - micros(start, from)?),
+        (None, None) => Ok(micros(end, 0)? - micros(start, 0)?),
+        // one is an instant and the other isn't
+        _ => Err(DateError::UnsupportedTimeZone),
+    }
+}
+
+pub(crate) fn local<Dt: DateTime + Clone>(dt: &Dt) -> DateResult<(Dt::Date, Dt::Time)> {
+    let (_, date, time) = dt.clone().split().ok_or(DateError::MissingDate)?;
+    Ok((date, time))
+}
+
+fn month_number<D: Date>(date: &D) -> i64 {
+    i64::from(date.year()) * 12 + i64::from(date.month())
+}
+
+pub(crate) fn day_number<D: Date>(date: &D) -> DateResult<i64> {
+    civil::Date::new(date.year(), date.month(), date.day())
+        .map(civil::Date::to_days)
+        .ok_or(DateError::MissingDate)
+}
@@ -0,0 +1,253 @@
+use core::ops::Sub;
+use core::time::Duration;
+
+use crate::datetime::DateTime;
+use crate::{DateError, DateResult, Disambiguation, Interval};
+
+impl Interval {
+    /// The exact length of the interval from `base`, as the difference type of `base`.
+    ///
+    /// Days and months don't have a fixed length, so they're measured from `base`, at the same
+    /// time of day: "1 day" is 23 hours across a change to daylight saving time, and "1 month"
+    /// from the 31st of January is 28 or 29 days.
+    ///
+    /// ```
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use interim::Interval;
+    ///
+    /// let base = Utc.with_ymd_and_hms(2024, 1, 31, 9, 0, 0).unwrap();
+    /// assert_eq!(Interval::Months(1).duration_from(base), Ok(Duration::days(29)));
+    /// assert_eq!(Interval::Seconds(90).duration_from(base), Ok(Duration::seconds(90)));
+    /// ```
+    pub fn duration_from<Dt>(&self, base: Dt) -> DateResult<<Dt as Sub>::Output>
+    where
+        Dt: DateTime + Clone + Sub,
+    {
+        let end = self
+            .clone()
+            .offset_from(base.clone(), Disambiguation::default())?;
+        Ok(end - base)
+    }
+
+    /// The exact length of the interval from `base`, like [`Interval::duration_from`].
+    /// Negative intervals give [`DateError::UnsupportedDuration`].
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn std_duration_from(&self, base: std::time::SystemTime) -> DateResult<Duration> {
+        let end = self.clone().offset_from(base, Disambiguation::default())?;
+        end.duration_since(base)
+            .map_err(|_| DateError::UnsupportedDuration)
+    }
+}
+
+/// A number of seconds that isn't negative. Days and months don't have a fixed length, so
+/// they give [`DateError::UnsupportedDuration`]; use [`Interval::std_duration_from`] for them.
+impl TryFrom<Interval> for Duration {
+    type Error = DateError;
+
+    fn try_from(interval: Interval) -> DateResult<Self> {
+        match interval {
+            Interval::Seconds(secs) => u64::try_from(secs)
+                .map(Duration::from_secs)
+                .map_err(|_| DateError::UnsupportedDuration),
+            Interval::Days(_) | Interval::Months(_) => Err(DateError::UnsupportedDuration),
+        }
+    }
+}
+
+/// A whole number of seconds, up to the largest [`Interval::Seconds`]
+impl TryFrom<Duration> for Interval {
+    type Error = DateError;
+
+    fn try_from(duration: Duration) -> DateResult<Self> {
+        if duration.subsec_nanos() != 0 {
+            return Err(DateError::UnsupportedDuration);
+        }
+        i64::try_from(duration.as_secs())
+            .map(Interval::Seconds)
+            .map_err(|_| DateError::UnsupportedDuration)
+    }
+}
+
+#[cfg(feature = "chrono_0_4")]
+mod chrono {
+    use chrono::Duration;
+
+    use crate::{DateError, DateResult, Interval};
+
+    /// A number of seconds, within chrono's range of about 292 million years. Days and months
+    /// don't have a fixed length, so they give [`DateError::UnsupportedDuration`];
+    /// use [`Interval::duration_from`] for them.
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono_0_4")))]
+    impl TryFrom<Interval> for Duration {
+        type Error = DateError;
+
+        fn try_from(interval: Interval) -> DateResult<Self> {
+            match interval {
+                Interval::Seconds(secs) => {
+                    Duration::try_seconds(secs).ok_or(DateError::UnsupportedDuration)
+                }
+                Interval::Days(_) | Interval::Months(_) => Err(DateError::UnsupportedDuration),
+            }
+        }
+    }
+
+    /// A whole number of seconds, up to the largest [`Interval::Seconds`]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chrono_0_4")))]
+    impl TryFrom<Duration> for Interval {
+        type Error = DateError;
+
+        fn try_from(duration: Duration) -> DateResult<Self> {
+            if duration.subsec_nanos() != 0 {
+                return Err(DateError::UnsupportedDuration);
+            }
+            Ok(Interval::Seconds(duration.num_seconds()))
+        }
+    }
+}
+
+#[cfg(feature = "time_0_3")]
+mod time {
+    use time::Duration;
+
+    use crate::{DateError, DateResult, Interval};
+
+    /// A number of seconds. Days and months don't have a fixed length, so they give
+    /// [`DateError::UnsupportedDuration`]; use [`Interval::duration_from`] for them.
+    #[cfg_attr(docsrs, doc(cfg(feature = "time_0_3")))]
+    impl TryFrom<Interval> for Duration {
+        type Error = DateError;
+
+        fn try_from(interval: Interval) -> DateResult<Self> {
+            match interval {
+                Interval::Seconds(secs) => Ok(Duration::seconds(secs)),
+                Interval::Days(_) | Interval::Months(_) => Err(DateError::UnsupportedDuration),
+            }
+        }
+    }
+
+    /// A whole number of seconds, up to the largest [`Interval::Seconds`]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time_0_3")))]
+    impl TryFrom<Duration> for Interval {
+        type Error = DateError;
+
+        fn try_from(duration: Duration) -> DateResult<Self> {
+            if duration.subsec_nanoseconds() != 0 {
+                return Err(DateError::UnsupportedDuration);
+            }
+            Ok(Interval::Seconds(duration.whole_seconds()))
+        }
+    }
+}
+
+#[cfg(feature = "jiff_0_1")]
+mod jiff_0_1 {
+    use jiff_0_1::Span;
+
+    use crate::{DateError, DateResult, Interval};
+
+    /// The same units, with the seconds split into hours, minutes and seconds.
+    ///
+    /// This is `TryFrom` rather than `From`, because a `Span` holds months and days exactly
+    /// but only within a limited range, while an `Interval` holds any `i64`. It fails with
+    /// [`DateError::UnsupportedDuration`] for intervals beyond the range of a `Span`,
+    /// like more than 19,998 years or 7,304,484 days.
+    #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_1")))]
+    impl TryFrom<Interval> for Span {
+        type Error = DateError;
+
+        fn try_from(interval: Interval) -> DateResult<Self> {
+            match interval {
+                Interval::Seconds(secs) => Span::new()
+                    .try_hours(secs / 3600)
+                    .and_then(|span| span.try_minutes(secs % 3600 / 60))
+                    .and_then(|span| span.try_seconds(secs % 60)),
+                Interval::Days(days) => Span::new().try_days(days),
+                Interval::Months(months) => Span::new().try_months(months),
+            }
+            .map_err(|_| DateError::UnsupportedDuration)
+        }
+    }
+
+    /// A span in a single group of units: years and months, weeks and days,
+    /// or hours, minutes and seconds
+    #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_1")))]
+    impl TryFrom<Span> for Interval {
+        type Error = DateError;
+
+        fn try_from(span: Span) -> DateResult<Self> {
+            if span.get_milliseconds() != 0
+                || span.get_microseconds() != 0
+                || span.get_nanoseconds() != 0
+            {
+                return Err(DateError::UnsupportedDuration);
+            }
+            let months = i64::from(span.get_years()) * 12 + i64::from(span.get_months());
+            let days = i64::from(span.get_weeks()) * 7 + i64::from(span.get_days());
+            let secs =
+                i64::from(span.get_hours()) * 3600 + span.get_minutes() * 60 + span.get_seconds();
+            match (months, days, secs) {
+                (months, 0, 0) => Ok(Interval::Months(months)),
+                (0, days, 0) => Ok(Interval::Days(days)),
+                (0, 0, secs) => Ok(Interval::Seconds(secs)),
+                _ => Err(DateError::UnsupportedDuration),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "jiff_0_2")]
+mod jiff_0_2 {
+    use jiff_0_2::Span;
+
+    use crate::{DateError, DateResult, Interval};
+
+    /// The same units, with the seconds split into hours, minutes and seconds.
+    ///
+    /// This is `TryFrom` rather than `From`, because a `Span` holds months and days exactly
+    /// but only within a limited range, while an `Interval` holds any `i64`. It fails with
+    /// [`DateError::UnsupportedDuration`] for intervals beyond the range of a `Span`,
+    /// like more than 19,998 years or 7,304,484 days.
+    #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_2")))]
+    impl TryFrom<Interval> for Span {
+        type Error = DateError;
+
+        fn try_from(interval: Interval) -> DateResult<Self> {
+            match interval {
+                Interval::Seconds(secs) => Span::new()
+                    .try_hours(secs / 3600)
+                    .and_then(|span| span.try_minutes(secs % 3600 / 60))
+                    .and_then(|span| span.try_seconds(secs % 60)),
+                Interval::Days(days) => Span::new().try_days(days),
+                Interval::Months(months) => Span::new().try_months(months),
+            }
+            .map_err(|_| DateError::UnsupportedDuration)
+        }
+    }
+
+    /// A span in a single group of units: years and months, weeks and days,
+    /// or hours, minutes and seconds
+    #[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_2")))]
+    impl TryFrom<Span> for Interval {
+        type Error = DateError;
+
+        fn try_from(span: Span) -> DateResult<Self> {
+            if span.get_milliseconds() != 0
+                || span.get_microseconds() != 0
+                || span.get_nanoseconds() != 0
+            {
+                return Err(DateError::UnsupportedDuration);
+            }
+            let months = i64::from(span.get_years()) * 12 + i64::from(span.get_months());
+            let days = i64::from(span.get_weeks()) * 7 + i64::from(span.get_days());
+            let secs =
+                i64::from(span.get_hours()) * 3600 + span.get_minutes() * 60 + span.get_seconds();
+            match (months, days, secs) {
+                (months, 0, 0) => Ok(Interval::Months(months)),
+                (0, days, 0) => Ok(Interval::Days(days)),
+                (0, 0, secs) => Ok(Interval::Seconds(secs)),
+                _ => Err(DateError::UnsupportedDuration),
+            }
+        }
+    }
+}
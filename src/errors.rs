@@ -14,6 +14,15 @@ pub enum DateError {
     UnexpectedDate,
     UnexpectedAbsoluteDate,
     UnexpectedTime,
+    ExpectedDateOnly,
+    ExpectedTimeOnly,
+
+    UnsupportedTimeZone,
+    NonexistentTime,
+    AmbiguousTime,
+
+    UnsupportedDuration,
+    Overflow(Span),
 }
 
 impl fmt::Display for DateError {
@@ -32,6 +41,21 @@ impl fmt::Display for DateError {
                 f.write_str("expected relative date, found an exact date")
             }
             DateError::UnexpectedTime => f.write_str("expected duration, found time"),
+            DateError::ExpectedDateOnly => f.write_str("expected a date, found a time of day"),
+            DateError::ExpectedTimeOnly => f.write_str("expected a time of day, found a date"),
+            DateError::UnsupportedTimeZone => {
+                f.write_str("the timezone cannot be represented by this date-time type")
+            }
+            DateError::NonexistentTime => {
+                f.write_str("the local time does not exist in the timezone")
+            }
+            DateError::AmbiguousTime => f.write_str("the local time is ambiguous in the timezone"),
+            DateError::UnsupportedDuration => {
+                f.write_str("the duration cannot be represented by this duration type")
+            }
+            DateError::Overflow(span) => {
+                write!(f, "the quantity at position {span:?} is too large")
+            }
         }
     }
 }
@@ -0,0 +1,315 @@
+use core::fmt;
+
+use crate::datetime::{Date, DateTime, Time};
+use crate::difference::{day_number, elapsed, local, on_clock_of};
+use crate::types::{ByName, Direction, TimeSpec};
+use crate::{DateError, DateResult, Dialect, Disambiguation, Interval, Options, Unit};
+
+pub(crate) const WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+pub(crate) const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Options for [`humanize_with`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HumanizeOptions {
+    dialect: Dialect,
+    precision: Unit,
+}
+
+impl HumanizeOptions {
+    /// The default options for the given [`Dialect`], to the minute
+    #[must_use]
+    pub const fn new(dialect: Dialect) -> Self {
+        Self {
+            dialect,
+            precision: Unit::Minute,
+        }
+    }
+
+    /// The smallest unit to write. Anything smaller is left out, so with [`Unit::Hour`] the
+    /// time is written on the hour, and [`Unit::Day`] or larger leaves out the time of day.
+    /// Defaults to [`Unit::Minute`].
+    #[must_use]
+    pub const fn precision(mut self, unit: Unit) -> Self {
+        self.precision = unit;
+        self
+    }
+}
+
+impl From<Dialect> for HumanizeOptions {
+    fn from(dialect: Dialect) -> Self {
+        Self::new(dialect)
+    }
+}
+
+/// A date-time described relative to the current time, from [`humanize`].
+/// `Display` writes the description, like "yesterday at 5pm".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Humanized {
+    date: Phrase,
+    time: Option<(u32, u32, Option<u32>)>,
+    dialect: Dialect,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Phrase {
+    Now,
+    Interval(Interval),
+    Today,
+    Yesterday,
+    Tomorrow,
+    WeekDay(u8, Direction),
+    DayMonth { day: u8, month: u8 },
+    Absolute { year: i32, month: u8, day: u8 },
+}
+
+impl fmt::Display for Humanized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let month = |month: u8| MONTHS[usize::from(month - 1)];
+        match self.date {
+            Phrase::Now => f.write_str("now")?,
+            Phrase::Interval(ref interval) => match interval {
+                Interval::Seconds(n) | Interval::Days(n) | Interval::Months(n) if *n < 0 => {
+                    write!(f, "{interval}")?;
+                }
+                _ => write!(f, "in {interval}")?,
+            },
+            Phrase::Today => f.write_str("today")?,
+            Phrase::Yesterday => f.write_str("yesterday")?,
+            Phrase::Tomorrow => f.write_str("tomorrow")?,
+            Phrase::WeekDay(weekday, direct) => {
+                let name = WEEKDAYS[usize::from(weekday)];
+                match direct {
+                    Direction::Last => write!(f, "last {name}")?,
+                    Direction::Here => f.write_str(name)?,
+                    Direction::Next => write!(f, "next {name}")?,
+                }
+            }
+            Phrase::DayMonth { day, month: m } => match self.dialect {
+                Dialect::Uk => write!(f, "on {day} {}", month(m))?,
+                Dialect::Us => write!(f, "on {} {day}", month(m))?,
+            },
+            Phrase::Absolute {
+                year,
+                month: m,
+                day,
+            } => match self.dialect {
+                Dialect::Uk => write!(f, "on {day} {} {year}", month(m))?,
+                Dialect::Us => write!(f, "on {} {day}, {year}", month(m))?,
+            },
+        }
+
+        let Some((hour, minute, second)) = self.time else {
+            return Ok(());
+        };
+        let (twelve, suffix) = match hour {
+            0 => (12, "am"),
+            1..=11 => (hour, "am"),
+            12 => (12, "pm"),
+            _ => (hour - 12, "pm"),
+        };
+        match (hour, minute, second.unwrap_or(0), self.dialect) {
+            (0, 0, 0, _) => f.write_str(" at midnight"),
+            (12, 0, 0, _) => f.write_str(" at noon"),
+            (_, 0, 0, _) => write!(f, " at {twelve}{suffix}"),
+            (_, _, _, Dialect::Uk) => match second {
+                Some(second) => write!(f, " at {hour:02}:{minute:02}:{second:02}"),
+                None => write!(f, " at {hour:02}:{minute:02}"),
+            },
+            (_, _, _, Dialect::Us) => match second {
+                Some(second) => write!(f, " at {twelve}:{minute:02}:{second:02}{suffix}"),
+                None => write!(f, " at {twelve}:{minute:02}{suffix}"),
+            },
+        }
+    }
+}
+
+/// Describe `dt` relative to `now`, like "5 minutes ago", "yesterday at 5pm", "next Tuesday"
+/// or "on 4 July 2023", to the minute. See [`humanize_with`].
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use interim::{humanize, parse_date_string, Dialect};
+///
+/// let now = Utc.with_ymd_and_hms(2024, 7, 10, 9, 30, 0).unwrap();
+/// let dt = Utc.with_ymd_and_hms(2024, 7, 9, 17, 0, 0).unwrap();
+/// let text = humanize(&dt, &now, Dialect::Uk).unwrap().to_string();
+/// assert_eq!(text, "yesterday at 5pm");
+/// assert_eq!(parse_date_string(&text, now, Dialect::Uk).unwrap(), dt);
+/// ```
+///
+/// # Errors
+///
+/// [`DateError::MissingDate`] if a date-time in between is out of range for `Dt`,
+/// or the year can't be written, and [`DateError::UnsupportedTimeZone`] if only one of `dt`
+/// and `now` has an offset from UTC.
+pub fn humanize<Dt: DateTime + Clone>(
+    dt: &Dt,
+    now: &Dt,
+    dialect: Dialect,
+) -> DateResult<Humanized> {
+    humanize_with(dt, now, HumanizeOptions::new(dialect))
+}
+
+/// Describe `dt` relative to `now`, in the most natural way that reads back as the same time.
+///
+/// Within an hour, this is the time from now, like "now", "5 minutes ago" or "in 20 minutes",
+/// to the minute or second. Otherwise it's the day, like "today", "yesterday", "tomorrow",
+/// the day of the week within a week or so, like "last Friday" or "Friday", and otherwise the
+/// date, like "on 4 July", with the year if it's not this year. Then the time, like "at 5pm"
+/// or "at 17:05", unless the precision is a day or more. The UK and US dialects write dates
+/// and times in their own ways, and "next Friday" is only written where the dialect reads it
+/// as that day.
+///
+/// Parsing the text with the same `now` and dialect gives back `dt`, with anything smaller than
+/// the precision left out: the time from now within an hour, and the time of day otherwise.
+/// `dt` is read on the clock of `now`, so it can be in another timezone, and a time in the hour
+/// that's repeated when the clocks go back is the time from now, like "in 2 hours".
+///
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use interim::{humanize_with, Dialect, HumanizeOptions, Unit};
+///
+/// // a Wednesday
+/// let now = Utc.with_ymd_and_hms(2024, 7, 10, 9, 30, 0).unwrap();
+/// let describe = |dt, options| humanize_with(&dt, &now, options).unwrap().to_string();
+/// let uk = HumanizeOptions::new(Dialect::Uk);
+/// let us = HumanizeOptions::new(Dialect::Us);
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 7, 10, 9, 12, 30).unwrap();
+/// assert_eq!(describe(dt, uk), "17 minutes ago");
+/// assert_eq!(describe(dt, uk.precision(Unit::Second)), "17 minutes 30 seconds ago");
+///
+/// let dt = Utc.with_ymd_and_hms(2024, 7, 16, 20, 5, 0).unwrap();
+/// assert_eq!(describe(dt, uk), "Tuesday at 20:05");
+/// assert_eq!(describe(dt, us), "Tuesday at 8:05pm");
+///
+/// // "next Tuesday" is the week after in the UK
+/// let dt = Utc.with_ymd_and_hms(2024, 7, 23, 20, 5, 0).unwrap();
+/// assert_eq!(describe(dt, uk), "next Tuesday at 20:05");
+/// assert_eq!(describe(dt, us), "on July 23 at 8:05pm");
+///
+/// let dt = Utc.with_ymd_and_hms(2023, 7, 4, 12, 0, 0).unwrap();
+/// assert_eq!(describe(dt, uk.precision(Unit::Day)), "on 4 July 2023");
+/// assert_eq!(describe(dt, us), "on July 4, 2023 at noon");
+/// ```
+///
+/// # Errors
+///
+/// Like [`humanize`].
+pub fn humanize_with<Dt: DateTime + Clone>(
+    dt: &Dt,
+    now: &Dt,
+    options: HumanizeOptions,
+) -> DateResult<Humanized> {
+    let HumanizeOptions { dialect, precision } = options;
+
+    let secs = elapsed(now, dt)?;
+    let size = if precision == Unit::Second { 1 } else { 60 };
+    if precision <= Unit::Minute && secs.abs() < 3600 {
+        let secs = secs / size * size;
+        return Ok(Humanized {
+            date: if secs == 0 {
+                Phrase::Now
+            } else {
+                Phrase::Interval(Interval::Seconds(secs))
+            },
+            time: None,
+            dialect,
+        });
+    }
+
+    // read on the clock of `now`, which is how the text is read back
+    let dt = on_clock_of(dt, now)?;
+    let (tz, date, time) = dt.clone().split().ok_or(DateError::MissingDate)?;
+    if precision < Unit::Day
+        && Dt::new(tz, date.clone(), time.clone(), Disambiguation::Compatible)?.utc_offset()
+            != dt.utc_offset()
+    {
+        // the hour that's repeated when the clocks go back reads back as the first time,
+        // so the second is the time from now
+        let size = if precision == Unit::Hour { 3600 } else { size };
+        return Ok(Humanized {
+            date: Phrase::Interval(Interval::Seconds(secs / size * size)),
+            time: None,
+            dialect,
+        });
+    }
+    let (today, _) = local(now)?;
+    let time = if precision < Unit::Day {
+        let (hour, minute, second) = (
+            u32::from(time.hour()),
+            u32::from(time.minute()),
+            u32::from(time.second()),
+        );
+        Some(match precision {
+            Unit::Second => (hour, minute, Some(second)),
+            Unit::Minute => (hour, minute, None),
+            _ => (hour, 0, None),
+        })
+    } else {
+        None
+    };
+    let ts = time.map(|(hour, minute, second)| TimeSpec::new(hour, minute, second.unwrap_or(0), 0));
+
+    let days = day_number(&date)? - day_number(&today)?;
+    let phrase = match days {
+        0 => Phrase::Today,
+        -1 => Phrase::Yesterday,
+        1 => Phrase::Tomorrow,
+        _ => {
+            // whichever of 'Friday', 'last Friday' and 'next Friday' gives this date,
+            // within a couple of weeks
+            let weekday = date.weekday();
+            let direction = [Direction::Here, Direction::Last, Direction::Next]
+                .into_iter()
+                .filter(|_| days.abs() < 14)
+                .find(|&direct| {
+                    ByName::WeekDay(weekday)
+                        .into_date_time(now.clone(), ts.clone(), Options::new(dialect), direct)
+                        .is_ok_and(|result| local(&result).is_ok_and(|(local, _)| local == date))
+                });
+            match direction {
+                Some(direct) => Phrase::WeekDay(weekday, direct),
+                None if date.year() == today.year() => Phrase::DayMonth {
+                    day: date.day(),
+                    month: date.month(),
+                },
+                // only years of up to 4 digits are read back
+                None if (0..=9999).contains(&date.year()) => Phrase::Absolute {
+                    year: date.year(),
+                    month: date.month(),
+                    day: date.day(),
+                },
+                None => return Err(DateError::MissingDate),
+            }
+        }
+    };
+
+    Ok(Humanized {
+        date: phrase,
+        time,
+        dialect,
+    })
+}
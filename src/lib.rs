@@ -14,11 +14,14 @@
 //!
 //! ## Features
 //!
-//! * `std`: This crate is `no_std` compatible. Disable the default-features to disable the std-lib features (just error reporting)
+//! * `std`: This crate is `no_std` compatible. Disable the default-features to disable the std-lib features (error reporting, `SystemTime` support, and chrono's `Local` timezone with `chrono_0_4`)
 //! * `time_0_3`: This crate is compatible with the [time crate](https://github.com/time-rs/time).
 //! * `chrono_0_4`: This crate is compatible with the [chrono crate](https://github.com/chronotope/chrono).
 //! * `jiff_0_1`: This crate is compatible with the v0.1 [jiff crate](https://github.com/BurntSushi/jiff).
 //! * `jiff_0_2`: This crate is compatible with the v0.2 [jiff crate](https://github.com/BurntSushi/jiff).
+//! * `chrono_tz_0_10`: Timezone names in the input are looked up with the [chrono-tz crate](https://github.com/chronotope/chrono-tz), if `jiff_0_2` is not enabled, and chrono date-times can be in a `chrono_tz::Tz`. Enables `chrono_0_4`.
+//! * `serde`: `Serialize` and `Deserialize` for [`Interval`] and [`Expression`], and [helpers](crate::serde) for reading durations from text. Enables `std`.
+//! * `clap`: [Value parsers](crate::clap) for reading dates and durations from command-line arguments. Enables `std`, and chrono's `clock` feature with `chrono_0_4`.
 //!
 //! ## Supported Formats
 //!
@@ -53,6 +56,67 @@
 //! second (like 18:03:40) or 'informal' like 6.03pm. So one gets "next friday 8pm' and so
 //! forth.
 //!
+//! Dates may start with "on", times with "at", and durations from now with "in", like "on 4 July at 9am"
+//! or "in 2 hours".
+//!
+//! Times may be followed by a timezone: a numeric offset like "+05:30", "+0530" or "+05",
+//! "Z", "UTC" or "GMT" with an optional offset ("GMT+1"), or a common abbreviation like
+//! "PST" or "CET". Some abbreviations are ambiguous, so "CST" and "IST" are resolved by
+//! the `Dialect`: US Central and India Standard Time for `Dialect::Us`, China Standard Time and
+//! Irish Standard Time for `Dialect::Uk`. The words "noon" and "midnight" are also understood.
+//! The result is the exact instant described, given in the timezone of the base time.
+//! With `Options::keep_offset`, it is given in the offset or timezone from the input instead.
+//!
+//! With the `jiff_0_2` or `chrono_tz_0_10` features, IANA timezone names like "Europe/London"
+//! are accepted too, as well as some city names after "in", like "tomorrow 9am in Tokyo".
+//! The time is read as the wall-clock time in that zone, using the DST rules for that date,
+//! and the result is given in the timezone of the base time. jiff looks up zones in its own
+//! timezone database, so this needs one of jiff's `tzdb` features or `tz-system`.
+//!
+//! Around daylight saving transitions, a local time may be skipped or repeated, like 01:30
+//! in London on the day the clocks go forward or back. By default, a skipped time is moved
+//! forward by the length of the gap, and a repeated time uses its earlier occurrence.
+//! `Options::disambiguation` chooses the earlier or later time instead, or rejects these times
+//! with `DateError::NonexistentTime` or `DateError::AmbiguousTime`. This applies to zones given
+//! in the input as well as the timezone of the base time.
+//!
+//! `time::OffsetDateTime` only has a fixed offset, so relative dates keep the offset of the base
+//! time across a DST change. `datetime::ZonedDateTime` pairs it with a function or closure giving
+//! the zone's offset at any instant, like one built on the `time-tz` or `tzdb` crates, and uses the
+//! offset in effect for each computed local time.
+//!
+//! Finally, the result can be converted into another timezone with a trailing `in <zone>` or
+//! `to <zone>`, like "9am PST in CET" or "now in UTC". When "in" directly follows a time without a
+//! timezone, the time is read in that zone instead, so "9am in CET to UTC" converts 9am in CET into
+//! UTC. Converting needs a date-time type that can represent the target zone:
+//! `time::OffsetDateTime` and `ZonedDateTime` use the offset in effect at that instant, and jiff's
+//! `Zoned` takes the zone itself. chrono's `DateTime<FixedOffset>` works like `OffsetDateTime`,
+//! `DateTime<Utc>` keeps the same instant, and `DateTime<chrono_tz::Tz>` takes named zones and UTC
+//! with the `chrono_tz_0_10` feature, while `DateTime<Local>` gives
+//! `DateError::UnsupportedTimeZone`.
+//!
+//! Unix timestamps are accepted in the GNU style, "@1700000000" or "@1700000000.123".
+//! With [`Options::bare_timestamps`], a bare integer of 5 or more digits is also treated as a
+//! timestamp, and is read as seconds, milliseconds or microseconds depending on its magnitude.
+//! The resulting instant is given in the timezone of the base time.
+//!
+//! Date-times without a timezone can be used as the base time too: `chrono::NaiveDateTime`,
+//! `time::PrimitiveDateTime` and jiff's `civil::DateTime`. Inputs with a timezone, an offset
+//! or a unix timestamp describe an exact instant, so they give `DateError::UnsupportedTimeZone`
+//! for these types.
+//!
+//! Instants can be used directly as well, with dates and times worked out in UTC:
+//! jiff's `Timestamp` and `time::UtcDateTime`. So "next friday 8pm" is 8pm UTC on that Friday.
+//!
+//! Without any of the date-time crates, the `civil` module has a small built-in calendar, with
+//! `civil::DateTime` at a fixed offset from UTC. With the `std` feature, `std::time::SystemTime`
+//! can be used too, with dates and times in UTC, so `parse_date_string("tomorrow 9am", SystemTime::now(), Dialect::Uk)`
+//! needs no other dependencies.
+//!
+//! Other date-time types can be used by implementing the traits in `datetime::v1`, which are
+//! versioned separately from the crate's internal traits. `conformance::Harness` runs the crate's
+//! own test suite against an implementation.
+//!
 //! ## API
 //!
 //! There are two entry points: `parse_date_string` and `parse_duration`. The
@@ -71,6 +135,14 @@
 //! There is a little command-line program `parse-date` in the `examples` folder which can be used to play
 //! with these expressions.
 //!
+//! For inputs that should only be a date or only a time, `parse_date` gives the date part, like
+//! "next friday", and `parse_time_of_day` gives the time of day, like "3:30pm". Inputs with the
+//! other part give `DateError::ExpectedDateOnly` or `DateError::ExpectedTimeOnly`.
+//!
+//! To evaluate the same text against many base times, `Expression::parse` parses it once, and
+//! `Expression::evaluate` gives the date-time for each base time without parsing it again.
+//! `Expression::ast` gives the parse tree, to inspect what the text describes and where.
+//!
 //! The other function, `parse_duration`, lets you access just the relative part
 //! of a string like 'two days ago' or '12 hours'. If successful, returns an
 //! `Interval`, which is a number of seconds, days, or months.
@@ -80,6 +152,46 @@
 //!
 //! assert_eq!(parse_duration("15m ago").unwrap(), Interval::Seconds(-15 * 60));
 //! ```
+//!
+//! Amounts of the same kind of unit can be combined, like "1 year 6 months" or "1h30m".
+//! `Interval` implements `FromStr` with `parse_duration`, and `Display` writes it back in
+//! the largest units it's a whole number of, or compactly with `{:#}`:
+//!
+//! ```
+//! use interim::Interval;
+//!
+//! let interval: Interval = "90m".parse().unwrap();
+//! assert_eq!(interval.to_string(), "1 hour 30 minutes");
+//! assert_eq!(format!("{interval:#}"), "1h30m");
+//! ```
+//!
+//! Intervals of seconds convert to and from `std::time::Duration` and the duration types of
+//! chrono and time with `TryFrom`, and intervals of any unit convert to and from jiff's `Span`. Days and
+//! months don't have a fixed length, so `Interval::duration_from` measures them from a base
+//! date-time, like 23 hours for "1 day" across a change to daylight saving time.
+//!
+//! `Interval::add_to` and `Interval::sub_from` apply an interval to any supported date-time,
+//! the same way as relative dates in the input. Adding months keeps the day of the month where
+//! it exists, and otherwise gives the last day of the month, so "30 Jan" plus 1 month is
+//! "28 Feb" or "29 Feb". `checked_add_to` and `checked_sub_from` give `None` out of range,
+//! and intervals in the same unit can be combined with `checked_add` and `checked_sub`.
+//!
+//! `difference` goes the other way, giving the calendar difference between two date-times, like
+//! "1 year 1 month 1 day 8 hours", for ages and time remaining. Months, days and the rest are
+//! counted the same way as `add_to` adds them. `difference_with` sets the largest and smallest
+//! units, and how the smallest unit is rounded.
+//!
+//! `humanize` describes a date-time relative to now, the way people write it, like "5 minutes ago",
+//! "yesterday at 5pm", "next Tuesday" or "on 4 July 2023". The text parses back to the same time with
+//! the same dialect, to the precision set with `humanize_with`.
+//!
+//! `Interval::approximate` and `Difference::approximate` round a duration to its most significant
+//! unit for notifications, like "a few seconds", "about an hour" for 45 minutes or
+//! "a day" for 26 hours. `approximate_with` sets the threshold for each unit, and can keep two units.
+//!
+//! `canonicalize` writes the text in a canonical form, like "next friday 20:00" for "next fri 8pm",
+//! for logs and deduplication. It's worked out from what the text says without evaluating it, and
+//! parses back to the same expression in either dialect.
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![no_std]
 #![warn(clippy::pedantic)]
@@ -100,14 +212,39 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+mod approximate;
+/// The parse tree of a date-time expression
+pub mod ast;
+mod canonical;
+/// A minimal calendar, for when no other date-time crate is available
+pub mod civil;
+/// Value parsers for command-line arguments
+#[cfg(feature = "clap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "clap")))]
+pub mod clap;
+/// A test suite for date-time implementations
+#[cfg(feature = "std")]
+pub mod conformance;
 /// A collection of traits to abstract over date-time implementations
 pub mod datetime;
+mod difference;
+mod duration;
 mod errors;
+mod humanize;
 mod parser;
+/// Serialize and deserialize durations and expressions as text
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde;
 mod types;
+mod tz;
 
+pub use approximate::{Approximate, ApproximateOptions};
+pub use canonical::{canonicalize, canonicalize_with, Canonical};
 use datetime::DateTime;
+pub use difference::{difference, difference_with, Difference, DifferenceOptions, Rounding, Unit};
 pub use errors::{DateError, DateResult};
+pub use humanize::{humanize, humanize_with, HumanizeOptions, Humanized};
 pub use types::Interval;
 use types::{DateSpec, DateTimeSpec};
 
@@ -118,6 +255,86 @@ pub enum Dialect {
     Us,
 }
 
+/// How to resolve a local time that doesn't exist, or exists twice, in a timezone.
+///
+/// Around daylight saving transitions, clocks skip forward and leave a gap (in
+/// `Europe/London`, 2018-03-25 01:30 never happened), or fall back and repeat an
+/// hour (2018-10-28 01:30 happened twice).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Disambiguation {
+    /// Pick the later time in a gap, and the earlier time in an overlap.
+    /// This matches RFC 5545 and most other date-time libraries.
+    #[default]
+    Compatible,
+    /// Pick the earlier of the two possible times
+    Earlier,
+    /// Pick the later of the two possible times
+    Later,
+    /// Return [`DateError::NonexistentTime`] or [`DateError::AmbiguousTime`]
+    Reject,
+}
+
+/// Configuration for [`parse_date_string_with`].
+///
+/// ```
+/// use interim::{Dialect, Options};
+///
+/// let options = Options::new(Dialect::Uk).bare_timestamps(true);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[must_use]
+pub struct Options {
+    pub(crate) dialect: Dialect,
+    pub(crate) bare_timestamps: bool,
+    pub(crate) disambiguation: Disambiguation,
+    pub(crate) keep_offset: bool,
+}
+
+impl Options {
+    /// The default options for the given [`Dialect`]
+    pub const fn new(dialect: Dialect) -> Self {
+        Self {
+            dialect,
+            bare_timestamps: false,
+            disambiguation: Disambiguation::Compatible,
+            keep_offset: false,
+        }
+    }
+
+    /// Accept bare integers of 5 or more digits as unix timestamps.
+    ///
+    /// The unit is detected by magnitude: values below 10<sup>11</sup> are seconds,
+    /// below 10<sup>14</sup> are milliseconds, and below 10<sup>17</sup> are microseconds.
+    pub const fn bare_timestamps(mut self, enabled: bool) -> Self {
+        self.bare_timestamps = enabled;
+        self
+    }
+
+    /// How to resolve local times that fall in a daylight saving gap or overlap.
+    /// Defaults to [`Disambiguation::Compatible`].
+    pub const fn disambiguation(mut self, disambiguation: Disambiguation) -> Self {
+        self.disambiguation = disambiguation;
+        self
+    }
+
+    /// Give the result in the offset or timezone written after the time, like
+    /// "9am +05:30" or "9am Europe/London", rather than the timezone of the base time.
+    ///
+    /// This converts the result in the same way as a trailing `in <zone>`, so it gives
+    /// [`DateError::UnsupportedTimeZone`] for types that can't represent the zone, like
+    /// chrono's `DateTime<Local>`, or a `DateTime<chrono_tz::Tz>` given a bare offset.
+    pub const fn keep_offset(mut self, enabled: bool) -> Self {
+        self.keep_offset = enabled;
+        self
+    }
+}
+
+impl From<Dialect> for Options {
+    fn from(dialect: Dialect) -> Self {
+        Self::new(dialect)
+    }
+}
+
 /// Parse a date-time from the text, potentially relative to `now`. Accepts
 /// a [`Dialect`] to support some slightly different text parsing behaviour.
 ///
@@ -131,22 +348,171 @@ pub enum Dialect {
 /// assert_eq!(this_friday, Utc.with_ymd_and_hms(2022, 9, 23, 20, 0, 0).unwrap());
 /// ```
 pub fn parse_date_string<Dt: DateTime>(s: &str, now: Dt, dialect: Dialect) -> DateResult<Dt> {
-    into_date_string(parser::DateParser::new(s).parse(dialect)?, now, dialect)
+    parse_date_string_with(s, now, Options::new(dialect))
 }
 
-fn into_date_string<Dt: DateTime>(d: DateTimeSpec, now: Dt, dialect: Dialect) -> DateResult<Dt> {
+/// Parse a date-time from the text, potentially relative to `now`, with some
+/// extra configuration [`Options`].
+///
+/// ```
+/// use interim::{parse_date_string_with, Dialect, Options};
+/// use chrono::{Utc, TimeZone};
+///
+/// let now = Utc.with_ymd_and_hms(2022, 9, 17, 13, 27, 0).unwrap();
+/// let options = Options::new(Dialect::Uk).bare_timestamps(true);
+/// let date = parse_date_string_with("1700000000000", now, options).unwrap();
+///
+/// assert_eq!(date, Utc.with_ymd_and_hms(2023, 11, 14, 22, 13, 20).unwrap());
+/// ```
+pub fn parse_date_string_with<Dt: DateTime>(s: &str, now: Dt, options: Options) -> DateResult<Dt> {
+    into_date_string(parser::DateParser::new(s).parse(options)?, now, options)
+}
+
+/// A date-time expression, parsed once and evaluated against many base times.
+///
+/// Parsing depends on the [`Dialect`] and the other [`Options`], like whether "8/11" is the
+/// 8th of November or August the 11th, so they are given when parsing rather than evaluating.
+///
+/// ```
+/// use interim::{Dialect, Expression};
+/// use chrono::{Utc, TimeZone};
+///
+/// let expression = Expression::parse("next monday 9am", Dialect::Uk).unwrap();
+///
+/// let now = Utc.with_ymd_and_hms(2022, 9, 17, 13, 27, 0).unwrap();
+/// assert_eq!(expression.evaluate(now), Ok(Utc.with_ymd_and_hms(2022, 9, 26, 9, 0, 0).unwrap()));
+/// let now = Utc.with_ymd_and_hms(2022, 9, 28, 8, 0, 0).unwrap();
+/// assert_eq!(expression.evaluate(now), Ok(Utc.with_ymd_and_hms(2022, 10, 10, 9, 0, 0).unwrap()));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Expression {
+    spec: DateTimeSpec,
+    options: Options,
+    #[cfg(feature = "std")]
+    source: std::string::String,
+}
+
+impl Expression {
+    /// Parse the text, with a [`Dialect`] or some extra configuration [`Options`]
+    pub fn parse(s: &str, options: impl Into<Options>) -> DateResult<Self> {
+        let options = options.into();
+        let spec = parser::DateParser::new(s).parse(options)?;
+        Ok(Self {
+            spec,
+            options,
+            #[cfg(feature = "std")]
+            source: s.into(),
+        })
+    }
+
+    /// The text this expression was parsed from
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// The date-time this expression describes, potentially relative to `now`.
+    ///
+    /// This gives the same result as [`parse_date_string_with`] on the original text.
+    pub fn evaluate<Dt: DateTime>(&self, now: Dt) -> DateResult<Dt> {
+        into_date_string(self.spec.clone(), now, self.options)
+    }
+
+    /// The parse tree, to inspect what the text describes
+    #[must_use]
+    pub fn ast(&self) -> ast::Ast<'_> {
+        ast::Ast::new(&self.spec)
+    }
+}
+
+fn into_date_string<Dt: DateTime>(d: DateTimeSpec, now: Dt, options: Options) -> DateResult<Dt> {
+    // an explicit conversion wins over the zone the time was given in
+    let convert = match (d.convert, &d.time) {
+        (Some(zone), _) => Some(zone),
+        (None, Some(tspec)) if options.keep_offset => tspec.zone.clone(),
+        (None, _) => None,
+    };
+
     // we may have explicit hour:minute:sec
-    if let Some(dspec) = d.date {
-        dspec
-            .into_date_time(now, d.time, dialect)
-            .ok_or(DateError::MissingDate)
+    let date_time = if let Some(dspec) = d.date {
+        dspec.into_date_time(now, d.time, options)?
     } else if let Some(tspec) = d.time {
-        let (tz, date, _) = now.split();
+        let (tz, date, _) = now.split().ok_or(DateError::MissingDate)?;
         // no date, use todays date
-        tspec.into_date_time(tz, date).ok_or(DateError::MissingTime)
+        tspec.into_date_time(tz, date, options.disambiguation)?
     } else {
-        Err(DateError::MissingTime)
+        return Err(DateError::MissingTime);
+    };
+
+    match convert {
+        Some(zone) => date_time
+            .to_zone(&zone)
+            .ok_or(DateError::UnsupportedTimeZone),
+        None => Ok(date_time),
+    }
+}
+
+/// Parse a date from the text, potentially relative to `now`, like "next friday".
+///
+/// Inputs with a time of day give [`DateError::ExpectedDateOnly`]. Relative inputs
+/// like "36 hours" and unix timestamps give the date of the resulting date-time.
+///
+/// ```
+/// use interim::{parse_date, Dialect};
+/// use chrono::{NaiveDate, Utc, TimeZone};
+///
+/// let now = Utc.with_ymd_and_hms(2022, 9, 17, 13, 27, 0).unwrap();
+/// let next_friday = parse_date("next friday", now, Dialect::Us).unwrap();
+///
+/// assert_eq!(next_friday, NaiveDate::from_ymd_opt(2022, 9, 23).unwrap());
+/// ```
+pub fn parse_date<Dt: DateTime>(s: &str, now: Dt, dialect: Dialect) -> DateResult<Dt::Date> {
+    let options = Options::new(dialect);
+    let d = parser::DateParser::new(s).parse(options)?;
+
+    if d.time.is_some() {
+        return Err(DateError::ExpectedDateOnly);
+    }
+    if d.date.is_none() {
+        return Err(DateError::MissingDate);
+    }
+
+    let (_, date, _) = into_date_string(d, now, options)?
+        .split()
+        .ok_or(DateError::MissingDate)?;
+    Ok(date)
+}
+
+/// Parse a time of day from the text, like "3:30pm".
+///
+/// Inputs with a date give [`DateError::ExpectedTimeOnly`]. A time given with a timezone
+/// is the time of day in the timezone of `now`, so "9am UTC" may be "10:00" in London.
+///
+/// ```
+/// use interim::{parse_time_of_day, Dialect};
+/// use chrono::{NaiveTime, Utc, TimeZone};
+///
+/// let now = Utc.with_ymd_and_hms(2022, 9, 17, 13, 27, 0).unwrap();
+/// let time = parse_time_of_day("3:30pm", now, Dialect::Uk).unwrap();
+///
+/// assert_eq!(time, NaiveTime::from_hms_opt(15, 30, 0).unwrap());
+/// ```
+pub fn parse_time_of_day<Dt: DateTime>(s: &str, now: Dt, dialect: Dialect) -> DateResult<Dt::Time> {
+    let options = Options::new(dialect);
+    let d = parser::DateParser::new(s).parse(options)?;
+
+    if d.date.is_some() {
+        return Err(DateError::ExpectedTimeOnly);
     }
+    if d.time.is_none() {
+        return Err(DateError::MissingTime);
+    }
+
+    let (_, _, time) = into_date_string(d, now, options)?
+        .split()
+        .ok_or(DateError::MissingDate)?;
+    Ok(time)
 }
 
 /// Parse an [`Interval`] from the text
@@ -163,15 +529,21 @@ fn into_date_string<Dt: DateTime>(d: DateTimeSpec, now: Dt, dialect: Dialect) ->
 /// assert_eq!(minutes, Interval::Seconds(10*60));
 /// ```
 pub fn parse_duration(s: &str) -> DateResult<Interval> {
-    let d = parser::DateParser::new(s).parse(Dialect::Uk)?;
+    let d = parser::DateParser::new(s).parse(Options::new(Dialect::Uk))?;
 
     if d.time.is_some() {
         return Err(DateError::UnexpectedTime);
     }
+    // a duration isn't in any timezone
+    if d.convert.is_some() {
+        return Err(DateError::ExpectedToken("end of duration", d.spans.convert));
+    }
 
     match d.date {
         Some(DateSpec::Relative(skip)) => Ok(skip),
-        Some(DateSpec::Absolute(_)) => Err(DateError::UnexpectedAbsoluteDate),
+        Some(DateSpec::Absolute(_) | DateSpec::Timestamp(_)) => {
+            Err(DateError::UnexpectedAbsoluteDate)
+        }
         Some(DateSpec::FromName(..)) => Err(DateError::UnexpectedDate),
         None => Err(DateError::MissingDate),
     }
@@ -1,11 +1,12 @@
-use logos::{Lexer, Logos};
+use logos::{Lexer, Logos, Span};
 
 use crate::{
     types::{
         month_name, time_unit, week_day, AbsDate, ByName, DateSpec, DateTimeSpec, Direction,
-        Lowercase, TimeSpec,
+        Lowercase, Spans, TimeSpec, Timestamp,
     },
-    DateError, DateResult, Dialect, Interval,
+    tz::{city, timezone_abbreviation, Zone},
+    DateError, DateResult, Dialect, Interval, Options,
 };
 
 // when we parse dates, there's often a bit of time parsed..
@@ -21,6 +22,7 @@ enum TimeKind {
 pub struct DateParser<'a> {
     s: Lexer<'a, Tokens>,
     maybe_time: Option<(u32, TimeKind)>,
+    spans: Spans,
 }
 
 #[derive(logos::Logos, Debug, PartialEq, Eq, Clone, Copy)]
@@ -29,7 +31,11 @@ enum Tokens {
     #[regex("[0-9]{1,4}", |lex| lex.slice().parse().map_err(|_| ()))]
     Number(u32),
 
-    #[regex("[a-zA-Z]+")]
+    // too long to be any date or time component, but can be a timestamp
+    #[regex("[0-9]{5,}", |lex| lex.slice().parse().map_err(|_| ()))]
+    LongNumber(u64),
+
+    #[regex("[a-zA-Z][a-zA-Z_]*")]
     Ident,
 
     // punctuation
@@ -45,6 +51,8 @@ enum Tokens {
     Comma,
     #[token("+")]
     Plus,
+    #[token("@")]
+    At,
 }
 
 const NOW: Lowercase = Lowercase::literal("now");
@@ -56,16 +64,56 @@ const LAST: Lowercase = Lowercase::literal("last");
 const THIS: Lowercase = Lowercase::literal("this");
 const AM: Lowercase = Lowercase::literal("am");
 const PM: Lowercase = Lowercase::literal("pm");
+const NOON: Lowercase = Lowercase::literal("noon");
+const MIDNIGHT: Lowercase = Lowercase::literal("midnight");
 const Z: Lowercase = Lowercase::literal("z");
+const UTC: Lowercase = Lowercase::literal("utc");
+const GMT: Lowercase = Lowercase::literal("gmt");
+const IN: Lowercase = Lowercase::literal("in");
+const TO: Lowercase = Lowercase::literal("to");
+const AT: Lowercase = Lowercase::literal("at");
+const ON: Lowercase = Lowercase::literal("on");
+
+// an amount of a unit in the smallest unit of its kind, like 7200 seconds
+// for '2 hours' or 24 months for '2 years'
+fn amount(unit: &Interval, n: u64, span: Span) -> DateResult<i128> {
+    let size = match *unit {
+        Interval::Seconds(size) | Interval::Days(size) | Interval::Months(size) => size,
+    };
+    let amount = i128::from(n) * i128::from(size);
+    // as large as an interval can be when it's negative
+    if amount > -i128::from(i64::MIN) {
+        return Err(DateError::Overflow(span));
+    }
+    Ok(amount)
+}
+
+// the number of microseconds in a fractional second, given the digits after the decimal point.
+// We only care for microsecond precision, so we only take the 6 most significant digits
+fn fraction_micros(digits: &str) -> u32 {
+    let mut micros = 0;
+    for i in 0..6 {
+        let digit = digits.as_bytes().get(i).map_or(0, |d| d - b'0');
+        micros = micros * 10 + u32::from(digit);
+    }
+    micros
+}
 
 impl<'a> DateParser<'a> {
     pub fn new(text: &'a str) -> DateParser<'a> {
         DateParser {
             s: Tokens::lexer(text),
             maybe_time: None,
+            spans: Spans::default(),
         }
     }
 
+    // the date parser looked ahead and saw an hour starting at `start`
+    fn maybe_time(&mut self, hour: u32, kind: TimeKind, start: usize) {
+        self.maybe_time = Some((hour, kind));
+        self.spans.time.start = start;
+    }
+
     fn next_num(&mut self) -> DateResult<u32> {
         match self.s.next() {
             Some(Ok(Tokens::Number(n))) => Ok(n),
@@ -74,6 +122,77 @@ impl<'a> DateParser<'a> {
         }
     }
 
+    // digits after a '.', as microseconds
+    fn next_fraction(&mut self) -> DateResult<u32> {
+        match self.s.next() {
+            Some(Ok(Tokens::Number(_) | Tokens::LongNumber(_))) => {
+                Ok(fraction_micros(self.s.slice()))
+            }
+            Some(_) => Err(DateError::ExpectedToken("number", self.s.span())),
+            None => Err(DateError::EndOfText("number")),
+        }
+    }
+
+    // We have already parsed the '@'
+    //
+    // @secs
+    // @-secs
+    // @secs.frac
+    fn timestamp(&mut self) -> DateResult<DateSpec> {
+        let s = self.s.clone();
+        let negative = if self.s.next() == Some(Ok(Tokens::Dash)) {
+            true
+        } else {
+            // backtrack
+            self.s = s;
+            false
+        };
+
+        let secs = match self.s.next() {
+            Some(Ok(Tokens::Number(n))) => i64::from(n),
+            Some(Ok(Tokens::LongNumber(n))) => i64::try_from(n)
+                .map_err(|_| DateError::ExpectedToken("timestamp", self.s.span()))?,
+            Some(_) => return Err(DateError::ExpectedToken("timestamp", self.s.span())),
+            None => return Err(DateError::EndOfText("timestamp")),
+        };
+
+        let s = self.s.clone();
+        let micros = if self.s.next() == Some(Ok(Tokens::Dot)) {
+            self.next_fraction()?
+        } else {
+            // backtrack
+            self.s = s;
+            0
+        };
+
+        Ok(DateSpec::Timestamp(match (negative, micros) {
+            (false, _) => Timestamp { secs, micros },
+            (true, 0) => Timestamp {
+                secs: -secs,
+                micros,
+            },
+            // -1.25s is -2s + 0.75s
+            (true, _) => Timestamp {
+                secs: -secs - 1,
+                micros: 1_000_000 - micros,
+            },
+        }))
+    }
+
+    // a bare integer timestamp, in seconds, milliseconds or microseconds
+    fn bare_timestamp(&mut self, n: u64) -> DateResult<DateSpec> {
+        let (secs, micros) = match n {
+            0..=99_999_999_999 => (n, 0),
+            100_000_000_000..=99_999_999_999_999 => (n / 1_000, (n % 1_000) * 1_000),
+            100_000_000_000_000..=99_999_999_999_999_999 => (n / 1_000_000, n % 1_000_000),
+            _ => return Err(DateError::ExpectedToken("timestamp", self.s.span())),
+        };
+        Ok(DateSpec::Timestamp(Timestamp {
+            secs: secs as i64,
+            micros: micros as u32,
+        }))
+    }
+
     fn iso_date(&mut self, year: i32) -> DateResult<DateSpec> {
         let month = self.next_num()?;
 
@@ -127,8 +246,9 @@ impl<'a> DateParser<'a> {
         }
     }
 
-    fn parse_date(&mut self, dialect: Dialect) -> DateResult<Option<DateSpec>> {
+    fn parse_date(&mut self, options: Options) -> DateResult<Option<DateSpec>> {
         let (sign, direct);
+        let s = self.s.clone();
         let token = match self.s.next() {
             Some(Ok(Tokens::Dash)) => {
                 sign = true;
@@ -141,6 +261,32 @@ impl<'a> DateParser<'a> {
                     NOW | TODAY => return Ok(Some(DateSpec::Relative(Interval::Days(0)))),
                     YESTERDAY => return Ok(Some(DateSpec::Relative(Interval::Days(-1)))),
                     TOMORROW => return Ok(Some(DateSpec::Relative(Interval::Days(1)))),
+                    NOON => {
+                        self.maybe_time(12, TimeKind::Pm, self.s.span().start);
+                        return Ok(None);
+                    }
+                    MIDNIGHT => {
+                        self.maybe_time(12, TimeKind::Am, self.s.span().start);
+                        return Ok(None);
+                    }
+                    // 'on 4 July'
+                    ON => return self.parse_date(options),
+                    // 'in 2 hours'
+                    IN => {
+                        let span = self.s.span();
+                        return match self.parse_date(options)? {
+                            Some(DateSpec::Relative(interval)) => {
+                                Ok(Some(DateSpec::Relative(interval)))
+                            }
+                            _ => Err(DateError::ExpectedToken("duration", span)),
+                        };
+                    }
+                    // 'at 8pm', there's only a time
+                    AT => {
+                        // backtrack
+                        self.s = s;
+                        return Ok(None);
+                    }
                     NEXT => Some(Direction::Next),
                     LAST => Some(Direction::Last),
                     THIS => Some(Direction::Here),
@@ -163,6 +309,29 @@ impl<'a> DateParser<'a> {
         match token {
             // date needs some token
             None => Err(DateError::EndOfText("empty date string")),
+            // @{timestamp}
+            Some(Ok(Tokens::At)) if !sign && direct.is_none() => Ok(Some(self.timestamp()?)),
+            // {timestamp}
+            Some(Ok(Tokens::LongNumber(n)))
+                if options.bare_timestamps && !sign && direct.is_none() =>
+            {
+                Ok(Some(self.bare_timestamp(n)?))
+            }
+            // {n} {interval}, with too many digits for a date
+            Some(Ok(Tokens::LongNumber(n))) => {
+                let span = self.s.span();
+                match self.s.next() {
+                    Some(Ok(Tokens::Ident)) => match time_unit(Lowercase::from(self.s.slice())) {
+                        Some(unit) => Ok(Some(self.relative(&unit, n, sign, span.start)?)),
+                        None => Err(DateError::ExpectedToken("time unit", self.s.span())),
+                    },
+                    _ => Err(DateError::ExpectedToken("date", span)),
+                }
+            }
+            // too many digits for any number
+            Some(Err(())) if self.s.slice().bytes().all(|b| b.is_ascii_digit()) => {
+                Err(DateError::Overflow(self.s.span()))
+            }
             // none of these characters begin a date or duration
             Some(
                 Ok(
@@ -171,7 +340,8 @@ impl<'a> DateParser<'a> {
                     | Tokens::Dash
                     | Tokens::Dot
                     | Tokens::Slash
-                    | Tokens::Plus,
+                    | Tokens::Plus
+                    | Tokens::At,
                 )
                 | Err(()),
             ) => Err(DateError::MissingDate),
@@ -187,6 +357,7 @@ impl<'a> DateParser<'a> {
                 if let Some(month) = month_name(Lowercase::from(self.s.slice())) {
                     // {month} [{day}, {year}]
                     // {month} [{day}] [{time}]
+                    let s = self.s.clone();
                     if let Some(Ok(Tokens::Number(day))) = self.s.next() {
                         let s = self.s.clone();
                         if self.s.next() == Some(Ok(Tokens::Comma)) {
@@ -204,6 +375,8 @@ impl<'a> DateParser<'a> {
                         }
                     } else {
                         // We only have a month name to work with
+                        // backtrack, there may be a time or conversion next
+                        self.s = s;
                         Ok(Some(DateSpec::FromName(ByName::MonthName(month), direct)))
                     }
                 } else if let Some(weekday) = week_day(Lowercase::from(self.s.slice())) {
@@ -211,12 +384,14 @@ impl<'a> DateParser<'a> {
                     // we'll try parse the time component later
                     Ok(Some(DateSpec::FromName(ByName::WeekDay(weekday), direct)))
                 } else if let Some(interval) = time_unit(Lowercase::from(self.s.slice())) {
-                    let interval = match direct {
-                        Direction::Last => interval * -1,
-                        #[allow(clippy::erasing_op)]
-                        Direction::Here => interval * 0,
-                        Direction::Next => interval,
+                    let factor = match direct {
+                        Direction::Last => -1,
+                        Direction::Here => 0,
+                        Direction::Next => 1,
                     };
+                    let interval = interval
+                        .checked_mul(factor)
+                        .ok_or_else(|| DateError::Overflow(self.s.span()))?;
                     Ok(Some(DateSpec::Relative(interval)))
                 } else {
                     Err(DateError::ExpectedToken(
@@ -231,13 +406,21 @@ impl<'a> DateParser<'a> {
             // {n} {interval}
             // {year}-{month}-{day}
             Some(Ok(Tokens::Number(n))) => {
+                let start = self.s.span().start;
                 match self.s.next() {
                     // if sign is set, we should expect something like '- 5 minutes'
                     None if sign => Err(DateError::EndOfText("duration")),
                     // we want a full date
-                    Some(Ok(Tokens::Comma | Tokens::Plus | Tokens::Number(_)) | Err(())) => {
-                        Err(DateError::ExpectedToken("date", self.s.span()))
-                    }
+                    Some(
+                        Ok(
+                            Tokens::Comma
+                            | Tokens::Plus
+                            | Tokens::At
+                            | Tokens::Number(_)
+                            | Tokens::LongNumber(_),
+                        )
+                        | Err(()),
+                    ) => Err(DateError::ExpectedToken("date", self.s.span())),
                     // if direct is set, we should expect a day or month to direct against
                     None | Some(Ok(Tokens::Colon | Tokens::Dot | Tokens::Dash))
                         if direct.is_some() =>
@@ -255,45 +438,28 @@ impl<'a> DateParser<'a> {
                         let name = Lowercase::from(self.s.slice());
                         if let Some(month) = month_name(name) {
                             let day = n;
+                            let s = self.s.clone();
                             if let Some(Ok(Tokens::Number(year))) = self.s.next() {
                                 // 4 July 2017
                                 let year = year as i32;
                                 Ok(Some(DateSpec::Absolute(AbsDate { year, month, day })))
                             } else {
                                 // 4 July
+                                // backtrack, there may be a time or conversion next
+                                self.s = s;
                                 Ok(Some(DateSpec::FromName(
                                     ByName::DayMonth { day, month },
                                     direct,
                                 )))
                             }
-                        } else if let Some(u) = time_unit(name) {
-                            let n = n as i32;
+                        } else if let Some(unit) = time_unit(name) {
                             // '2 days'
-                            if sign {
-                                Ok(Some(DateSpec::Relative(u * -n)))
-                            } else {
-                                match self.s.next() {
-                                    Some(Ok(Tokens::Ident))
-                                        if Lowercase::from(self.s.slice())
-                                            == Lowercase::literal("ago") =>
-                                    {
-                                        Ok(Some(DateSpec::Relative(u * -n)))
-                                    }
-                                    Some(Ok(Tokens::Ident)) => {
-                                        Err(DateError::ExpectedToken("'ago'", self.s.span()))
-                                    }
-                                    Some(Ok(Tokens::Number(h))) => {
-                                        self.maybe_time = Some((h, TimeKind::Unknown));
-                                        Ok(Some(DateSpec::Relative(u * n)))
-                                    }
-                                    _ => Ok(Some(DateSpec::Relative(u * n))),
-                                }
-                            }
+                            Ok(Some(self.relative(&unit, n.into(), sign, start)?))
                         } else if name == AM {
-                            self.maybe_time = Some((n, TimeKind::Am));
+                            self.maybe_time(n, TimeKind::Am, start);
                             Ok(None)
                         } else if name == PM {
-                            self.maybe_time = Some((n, TimeKind::Pm));
+                            self.maybe_time(n, TimeKind::Pm, start);
                             Ok(None)
                         } else {
                             Err(DateError::ExpectedToken(
@@ -303,17 +469,17 @@ impl<'a> DateParser<'a> {
                         }
                     }
                     Some(Ok(Tokens::Colon)) => {
-                        self.maybe_time = Some((n, TimeKind::Formal));
+                        self.maybe_time(n, TimeKind::Formal, start);
                         Ok(None)
                     }
                     Some(Ok(Tokens::Dot)) => {
-                        self.maybe_time = Some((n, TimeKind::Informal));
+                        self.maybe_time(n, TimeKind::Informal, start);
                         Ok(None)
                     }
                     Some(Ok(Tokens::Dash)) => Ok(Some(self.iso_date(n as i32)?)),
                     Some(Ok(Tokens::Slash)) => Ok(Some(self.informal_date(
                         n,
-                        dialect,
+                        options.dialect,
                         direct.unwrap_or(Direction::Here),
                     )?)),
                 }
@@ -321,104 +487,297 @@ impl<'a> DateParser<'a> {
         }
     }
 
+    // '2 days', '1 year 6 months ago', after the first amount and its unit
+    fn relative(
+        &mut self,
+        unit: &Interval,
+        n: u64,
+        sign: bool,
+        start: usize,
+    ) -> DateResult<DateSpec> {
+        let (unit, total) = self.amounts(unit, n, start)?;
+        let span = start..self.s.span().end;
+        let interval = |total: i128| {
+            i64::try_from(total)
+                .ok()
+                .and_then(|n| unit.checked_mul(n))
+                .ok_or_else(|| DateError::Overflow(span.clone()))
+        };
+        if sign {
+            return Ok(DateSpec::Relative(interval(-total)?));
+        }
+
+        let s = self.s.clone();
+        match self.s.next() {
+            Some(Ok(Tokens::Ident))
+                if Lowercase::from(self.s.slice()) == Lowercase::literal("ago") =>
+            {
+                Ok(DateSpec::Relative(interval(-total)?))
+            }
+            // a timezone conversion
+            Some(Ok(Tokens::Ident)) if matches!(Lowercase::from(self.s.slice()), IN | TO) => {
+                // backtrack
+                self.s = s;
+                Ok(DateSpec::Relative(interval(total)?))
+            }
+            Some(Ok(Tokens::Ident)) => Err(DateError::ExpectedToken("'ago'", self.s.span())),
+            Some(Ok(Tokens::Number(h))) => {
+                self.maybe_time(h, TimeKind::Unknown, self.s.span().start);
+                Ok(DateSpec::Relative(interval(total)?))
+            }
+            _ => Ok(DateSpec::Relative(interval(total)?)),
+        }
+    }
+
+    // the amounts in a duration like '1 year 6 months', which must all be the same kind of unit.
+    // Gives the smallest unit of the kind, and the total number of it. The total is wide enough
+    // to be negated, and each amount must fit in an interval on its own
+    fn amounts(&mut self, unit: &Interval, n: u64, start: usize) -> DateResult<(Interval, i128)> {
+        let mut total = amount(unit, n, start..self.s.span().end)?;
+        loop {
+            let s = self.s.clone();
+            let n = match self.s.next() {
+                Some(Ok(Tokens::Number(n))) => u64::from(n),
+                Some(Ok(Tokens::LongNumber(n))) => n,
+                _ => {
+                    self.s = s;
+                    break;
+                }
+            };
+            let start = self.s.span().start;
+            let next = match self.s.next() {
+                Some(Ok(Tokens::Ident)) => time_unit(Lowercase::from(self.s.slice())),
+                _ => None,
+            };
+            match next {
+                Some(next) if core::mem::discriminant(&next) == core::mem::discriminant(unit) => {
+                    let span = start..self.s.span().end;
+                    total = total
+                        .checked_add(amount(&next, n, span.clone())?)
+                        .ok_or(DateError::Overflow(span))?;
+                }
+                Some(_) => {
+                    return Err(DateError::ExpectedToken(
+                        "time unit of the same kind",
+                        self.s.span(),
+                    ))
+                }
+                // not part of the duration, like the hour in '2 days 9am'
+                None => {
+                    self.s = s;
+                    break;
+                }
+            }
+        }
+        let unit = match *unit {
+            Interval::Seconds(_) => Interval::Seconds(1),
+            Interval::Days(_) => Interval::Days(1),
+            Interval::Months(_) => Interval::Months(1),
+        };
+        Ok((unit, total))
+    }
+
+    // an optional am/pm suffix after the hour
+    fn am_pm(&mut self, hour: u32) -> u32 {
+        let s = self.s.clone();
+        if self.s.next() == Some(Ok(Tokens::Ident)) {
+            match Lowercase::from(self.s.slice()) {
+                // morning
+                AM if hour == 12 => return 0,
+                AM => return hour,
+                // afternoon
+                PM if hour == 12 => return 12,
+                PM => return hour + 12,
+                _ => {}
+            }
+        }
+        // backtrack
+        self.s = s;
+        hour
+    }
+
     fn formal_time(&mut self, hour: u32) -> DateResult<TimeSpec> {
         let min = self.next_num()?;
         let mut sec = 0;
         let mut micros = 0;
 
         // minute may be followed by [:secs][am|pm]
-        let tnext = match self.s.next() {
-            Some(Ok(Tokens::Colon)) => {
-                sec = self.next_num()?;
-                match self.s.next() {
-                    Some(Ok(Tokens::Dot)) => {
-                        // after a `.` implies these are subseconds.
-                        // We only care for microsecond precision, so let's
-                        // get only the 6 most significant digits
-                        micros = self.next_num()?;
-                        while micros > 1_000_000 {
-                            micros /= 10;
-                        }
-                        self.s.next()
-                    }
-                    t => t,
-                }
+        let s = self.s.clone();
+        if self.s.next() == Some(Ok(Tokens::Colon)) {
+            sec = self.next_num()?;
+            let s = self.s.clone();
+            if self.s.next() == Some(Ok(Tokens::Dot)) {
+                // after a `.` implies these are subseconds.
+                micros = self.next_fraction()?;
+            } else {
+                // backtrack
+                self.s = s;
             }
-            // we don't expect any of these after parsing minutes
-            Some(
-                Ok(Tokens::Dash | Tokens::Slash | Tokens::Dot | Tokens::Comma | Tokens::Plus)
-                | Err(()),
-            ) => {
-                return Err(DateError::ExpectedToken("':'", self.s.span()));
+        } else {
+            // backtrack
+            self.s = s;
+        }
+
+        let hour = self.am_pm(hour);
+        Ok(TimeSpec::new(hour, min, sec, micros))
+    }
+
+    fn informal_time(&mut self, hour: u32) -> DateResult<TimeSpec> {
+        let min = self.next_num()?;
+        let hour = self.am_pm(hour);
+        Ok(TimeSpec::new(hour, min, 0, 0))
+    }
+
+    // We have already parsed the '+' or '-'
+    //
+    // +hh
+    // +hhmm
+    // +hh:mm
+    fn offset(&mut self, sign: Tokens) -> DateResult<i64> {
+        let sign = if sign == Tokens::Dash { -1 } else { 1 };
+
+        // after a +/-, we expect a numerical offset.
+        let mut hours = self.next_num()?;
+
+        let s = self.s.clone();
+        let minutes = if self.s.next() == Some(Ok(Tokens::Colon)) {
+            // 02:00
+            //    ^^
+            self.next_num()?
+        } else {
+            // backtrack
+            self.s = s;
+            if self.s.slice().len() <= 2 {
+                // 02
+                0
+            } else {
+                // we should have the hours and minutes in the single number
+                // 0030
+                //   ^^
+                let minutes = hours % 100;
+                hours /= 100;
+                minutes
             }
-            t => t,
         };
+        // hours and minutes offset in seconds
+        let res = 60 * (minutes + 60 * hours);
+        Ok(i64::from(res) * sign)
+    }
 
-        match tnext {
+    // the optional timezone after a time
+    //
+    // +hh:mm
+    // {zone}
+    // in {zone}
+    fn timezone(&mut self, dialect: Dialect) -> DateResult<Option<Zone>> {
+        let s = self.s.clone();
+        match self.s.next() {
             // we need no timezone or hour offset. All good :)
-            None => Ok(TimeSpec::new(hour, min, sec, micros)),
+            None => Ok(None),
+            // no timezone, but a conversion
+            Some(Ok(Tokens::Ident)) if Lowercase::from(self.s.slice()) == TO => {
+                // backtrack
+                self.s = s;
+                Ok(None)
+            }
             // +/- timezone offset
             Some(Ok(tok @ (Tokens::Plus | Tokens::Dash))) => {
-                let sign = if tok == Tokens::Dash { -1 } else { 1 };
-
-                // after a +/-, we expect a numerical offset.
-                // either HH:MM or HHMM
-                let mut hours = self.next_num()?;
+                let start = self.s.span().start;
+                let zone = Zone::Fixed(self.offset(tok)?);
+                self.spans.zone = start..self.s.span().end;
+                Ok(Some(zone))
+            }
+            Some(Ok(Tokens::Ident)) if Lowercase::from(self.s.slice()) == IN => {
+                match self.s.next() {
+                    Some(Ok(Tokens::Ident)) => {
+                        let start = self.s.span().start;
+                        let zone = self.named_zone(dialect, true)?;
+                        self.spans.zone = start..self.s.span().end;
+                        Ok(Some(zone))
+                    }
+                    Some(_) => Err(DateError::ExpectedToken("timezone", self.s.span())),
+                    None => Err(DateError::EndOfText("timezone")),
+                }
+            }
+            Some(Ok(Tokens::Ident)) => {
+                let start = self.s.span().start;
+                let zone = self.named_zone(dialect, false)?;
+                self.spans.zone = start..self.s.span().end;
+                Ok(Some(zone))
+            }
+            Some(_) => Err(DateError::ExpectedToken("timezone", self.s.span())),
+        }
+    }
 
+    // We have already parsed the first identifier of the zone
+    //
+    // Z
+    // UTC[+hh:mm]
+    // GMT[+hh:mm]
+    // {abbreviation}
+    // {area}/{location}
+    // {city} (only after 'in')
+    fn named_zone(&mut self, dialect: Dialect, allow_city: bool) -> DateResult<Zone> {
+        let span = self.s.span();
+        match Lowercase::from(self.s.slice()) {
+            // 0-offset timezone
+            Z => return Ok(Zone::Fixed(0)),
+            // 0-offset timezone, with an optional offset
+            UTC | GMT => {
                 let s = self.s.clone();
-                let minutes = if self.s.next() != Some(Ok(Tokens::Colon)) {
-                    // backtrack, we should have the hours and minutes in the single number
-                    self.s = s;
-
-                    // 0030
-                    //   ^^
-                    let minutes = hours % 100;
-                    hours /= 100;
-                    minutes
+                return if let Some(Ok(tok @ (Tokens::Plus | Tokens::Dash))) = self.s.next() {
+                    self.offset(tok).map(Zone::Fixed)
                 } else {
-                    // 02:00
-                    //    ^^
-                    self.next_num()?
+                    // backtrack
+                    self.s = s;
+                    Ok(Zone::Fixed(0))
                 };
-                // hours and minutes offset in seconds
-                let res = 60 * (minutes + 60 * hours);
-                let offset = i64::from(res) * sign;
-                Ok(TimeSpec::new(hour, min, sec, micros).with_offset(offset))
             }
-            Some(Ok(Tokens::Ident)) => match Lowercase::from(self.s.slice()) {
-                // 0-offset timezone
-                Z => Ok(TimeSpec::new(hour, min, sec, micros).with_offset(0)),
-                // morning
-                AM if hour == 12 => Ok(TimeSpec::new(0, min, sec, micros)),
-                AM => Ok(TimeSpec::new(hour, min, sec, micros)),
-                // afternoon
-                PM if hour == 12 => Ok(TimeSpec::new(12, min, sec, micros)),
-                PM => Ok(TimeSpec::new(hour + 12, min, sec, micros)),
-                _ => Err(DateError::ExpectedToken("expected Z/am/pm", self.s.span())),
-            },
-            Some(
-                Ok(Tokens::Slash | Tokens::Colon | Tokens::Dot | Tokens::Comma | Tokens::Number(_))
-                | Err(()),
-            ) => Err(DateError::ExpectedToken("expected timezone", self.s.span())),
+            name => {
+                if let Some(offset) = timezone_abbreviation(name, dialect) {
+                    return Ok(Zone::Fixed(offset));
+                }
+            }
         }
-    }
 
-    fn informal_time(&mut self, hour: u32) -> DateResult<TimeSpec> {
-        let min = self.next_num()?;
+        // IANA names are made of adjacent tokens, eg 'America/Port-au-Prince'
+        let mut end = span.end;
+        let mut s = self.s.clone();
+        while let Some(Ok(_)) = s.next() {
+            if s.span().start != end || (end == span.end && s.slice() != "/") {
+                break;
+            }
+            end = s.span().end;
+            self.s = s.clone();
+        }
+        if end != span.end {
+            let name = &self.s.source()[span.start..end];
+            return Zone::named(name)
+                .ok_or(DateError::ExpectedToken("known timezone", span.start..end));
+        }
 
-        let hour = match self.s.next() {
-            None => hour,
-            Some(Ok(Tokens::Ident)) if Lowercase::from(self.s.slice()) == AM && hour == 12 => 0,
-            Some(Ok(Tokens::Ident)) if Lowercase::from(self.s.slice()) == AM => hour,
-            Some(Ok(Tokens::Ident)) if Lowercase::from(self.s.slice()) == PM && hour == 12 => 12,
-            Some(Ok(Tokens::Ident)) if Lowercase::from(self.s.slice()) == PM => hour + 12,
-            Some(_) => return Err(DateError::ExpectedToken("expected am/pm", self.s.span())),
-        };
+        if allow_city {
+            let first = self.s.slice();
+            // cities might be made of two words
+            let s = self.s.clone();
+            if let Some(Ok(Tokens::Ident)) = self.s.next() {
+                if let Some(name) = city(first, Some(self.s.slice())) {
+                    let span = span.start..self.s.span().end;
+                    return Zone::named(name)
+                        .ok_or(DateError::ExpectedToken("known timezone", span));
+                }
+            }
+            // backtrack
+            self.s = s;
+            if let Some(name) = city(first, None) {
+                return Zone::named(name).ok_or(DateError::ExpectedToken("known timezone", span));
+            }
+        }
 
-        Ok(TimeSpec::new(hour, min, 0, 0))
+        Err(DateError::ExpectedToken("timezone", span))
     }
 
-    pub fn parse_time(&mut self) -> DateResult<Option<TimeSpec>> {
+    fn time_of_day(&mut self) -> DateResult<Option<TimeSpec>> {
         // here the date parser looked ahead and saw an hour followed by some separator
         if let Some((h, kind)) = self.maybe_time {
             Ok(Some(match kind {
@@ -437,16 +796,43 @@ impl<'a> DateParser<'a> {
             }))
         } else {
             let s = self.s.clone();
-            if self.s.next() != Some(Ok(Tokens::Ident)) || self.s.slice() != "T" {
-                // backtrack if we weren't able to consume a 'T' time separator
-                self.s = s;
-            }
+            let at = match self.s.next() {
+                Some(Ok(Tokens::Ident)) if self.s.slice() == "T" => false,
+                Some(Ok(Tokens::Ident)) if Lowercase::from(self.s.slice()) == AT => true,
+                _ => {
+                    // backtrack if we weren't able to consume a 'T' time separator or 'at'
+                    self.s = s;
+                    false
+                }
+            };
 
             // we're parsing times so we should expect an hour number.
             // if we don't find one, then there's no time here
+            let s = self.s.clone();
             let hour = match self.s.next() {
+                None if at => return Err(DateError::EndOfText("time")),
                 None => return Ok(None),
-                Some(Ok(Tokens::Number(n))) => n,
+                Some(Ok(Tokens::Number(n))) => {
+                    self.spans.time.start = self.s.span().start;
+                    n
+                }
+                Some(Ok(Tokens::Ident)) => match Lowercase::from(self.s.slice()) {
+                    NOON => {
+                        self.spans.time = self.s.span();
+                        return Ok(Some(TimeSpec::new(12, 0, 0, 0)));
+                    }
+                    MIDNIGHT => {
+                        self.spans.time = self.s.span();
+                        return Ok(Some(TimeSpec::new(0, 0, 0, 0)));
+                    }
+                    // no time, but a timezone conversion
+                    IN | TO => {
+                        // backtrack
+                        self.s = s;
+                        return Ok(None);
+                    }
+                    _ => return Err(DateError::ExpectedToken("number", self.s.span())),
+                },
                 Some(_) => return Err(DateError::ExpectedToken("number", self.s.span())),
             };
 
@@ -456,8 +842,10 @@ impl<'a> DateParser<'a> {
                 // hh.mm
                 Some(Ok(Tokens::Dot)) => self.informal_time(hour).map(Some),
                 // 9am
-                Some(Ok(Tokens::Ident)) => match Lowercase::literal(self.s.slice()) {
+                Some(Ok(Tokens::Ident)) => match Lowercase::from(self.s.slice()) {
+                    AM if hour == 12 => Ok(Some(TimeSpec::new(0, 0, 0, 0))),
                     AM => Ok(Some(TimeSpec::new(hour, 0, 0, 0))),
+                    PM if hour == 12 => Ok(Some(TimeSpec::new(12, 0, 0, 0))),
                     PM => Ok(Some(TimeSpec::new(hour + 12, 0, 0, 0))),
                     _ => Err(DateError::ExpectedToken("am/pm", self.s.span())),
                 },
@@ -467,9 +855,80 @@ impl<'a> DateParser<'a> {
         }
     }
 
-    pub fn parse(&mut self, dialect: Dialect) -> DateResult<DateTimeSpec> {
-        let date = self.parse_date(dialect)?;
-        let time = self.parse_time()?;
-        Ok(DateTimeSpec { date, time })
+    pub fn parse_time(&mut self, dialect: Dialect) -> DateResult<Option<TimeSpec>> {
+        let Some(time) = self.time_of_day()? else {
+            return Ok(None);
+        };
+        self.spans.time.end = self.s.span().end;
+
+        // any time may be followed by a timezone
+        match self.timezone(dialect)? {
+            Some(zone) => Ok(Some(time.with_zone(zone))),
+            None => Ok(Some(time)),
+        }
+    }
+
+    // the optional conversion of the result into another timezone
+    //
+    // in {zone}
+    // to {zone}
+    fn conversion(&mut self, dialect: Dialect) -> DateResult<Option<Zone>> {
+        match self.s.next() {
+            None => Ok(None),
+            Some(Ok(Tokens::Ident)) if matches!(Lowercase::from(self.s.slice()), IN | TO) => {
+                let (zone, start) = match self.s.next() {
+                    Some(Ok(tok @ (Tokens::Plus | Tokens::Dash))) => {
+                        let start = self.s.span().start;
+                        (Zone::Fixed(self.offset(tok)?), start)
+                    }
+                    Some(Ok(Tokens::Ident)) => {
+                        let start = self.s.span().start;
+                        (self.named_zone(dialect, true)?, start)
+                    }
+                    Some(_) => return Err(DateError::ExpectedToken("timezone", self.s.span())),
+                    None => return Err(DateError::EndOfText("timezone")),
+                };
+                self.spans.convert = start..self.s.span().end;
+                Ok(Some(zone))
+            }
+            Some(_) => Err(DateError::ExpectedToken("'in' or 'to'", self.s.span())),
+        }
+    }
+
+    pub fn parse(&mut self, options: Options) -> DateResult<DateTimeSpec> {
+        let start = {
+            let mut s = self.s.clone();
+            s.next();
+            if s.slice().eq_ignore_ascii_case("on") {
+                s.next();
+            }
+            s.span().start
+        };
+        let date = self.parse_date(options)?;
+        // the date ends before any hour it looked ahead at
+        let end = match self.maybe_time {
+            Some(_) => {
+                start
+                    + self.s.source()[start..self.spans.time.start]
+                        .trim_end()
+                        .len()
+            }
+            None => self.s.span().end,
+        };
+        self.spans.date = start..end;
+
+        // a timestamp is already an exact instant, it cannot be followed by a time
+        let time = if let Some(DateSpec::Timestamp(_)) = date {
+            None
+        } else {
+            self.parse_time(options.dialect)?
+        };
+        let convert = self.conversion(options.dialect)?;
+        Ok(DateTimeSpec {
+            date,
+            time,
+            convert,
+            spans: core::mem::take(&mut self.spans),
+        })
     }
 }
@@ -0,0 +1,235 @@
+//! Serialize and deserialize durations and expressions as the text people write in configs.
+//!
+//! [`Interval`] and [`Expression`] implement `Serialize` and `Deserialize` using their
+//! text forms, and the modules here are for `#[serde(with = "...")]` fields of other types.
+//!
+//! ```
+//! use interim::{Expression, Interval};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Job {
+//!     every: Interval,
+//!     #[serde(with = "interim::serde::std_duration")]
+//!     timeout: std::time::Duration,
+//!     start: Expression,
+//! }
+//!
+//! let job: Job = serde_json::from_str(r#"{
+//!     "every": "2 weeks",
+//!     "timeout": "15m",
+//!     "start": "next monday 9am"
+//! }"#).unwrap();
+//! assert_eq!(job.every, Interval::Days(14));
+//! assert_eq!(job.timeout, std::time::Duration::from_secs(15 * 60));
+//!
+//! assert_eq!(
+//!     serde_json::to_string(&job).unwrap(),
+//!     r#"{"every":"2 weeks","timeout":"15 minutes","start":"next monday 9am"}"#,
+//! );
+//! ```
+//!
+//! Durations are read with [`parse_duration`](crate::parse_duration), and written like the
+//! `Display` form of [`Interval`], such as "1 minute 30 seconds" or "3 days ago". Only
+//! [`Interval`] and jiff's `Span` can hold months and years, so the other duration types refuse
+//! them, and count days as 24 hours. Durations with fractions of a second can't be written.
+//!
+//! Expressions are parsed with [`Dialect::Uk`], unless the field uses [`us_expression`](crate::serde::us_expression).
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::ser::{self, Serializer};
+use ::serde::{Deserialize, Serialize};
+
+use crate::{parse_duration, DateResult, Dialect, Expression, Interval};
+
+impl Serialize for Interval {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Interval {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(Parse(
+            parse_duration,
+            "a duration like \"15 minutes\" or \"2 weeks\"",
+            PhantomData,
+        ))
+    }
+}
+
+impl Serialize for Expression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Expression {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(Parse(
+            |s: &str| Expression::parse(s, Dialect::Uk),
+            "a date-time like \"next monday 9am\"",
+            PhantomData,
+        ))
+    }
+}
+
+/// Read [`Expression`]s in the US dialect, where "8/11" is August the 11th
+///
+/// ```
+/// use interim::Expression;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Holiday {
+///     #[serde(with = "interim::serde::us_expression")]
+///     date: Expression,
+/// }
+/// let holiday: Holiday = serde_json::from_str(r#"{ "date": "7/4" }"#).unwrap();
+/// ```
+pub mod us_expression {
+    use super::{Deserializer, Dialect, Expression, Parse, PhantomData, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        expression: &Expression,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        expression.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Expression, D::Error> {
+        deserializer.deserialize_str(Parse(
+            |s: &str| Expression::parse(s, Dialect::Us),
+            "a date-time like \"next monday 9am\"",
+            PhantomData,
+        ))
+    }
+}
+
+/// Read and write [`std::time::Duration`]s, which can't be negative
+pub mod std_duration {
+    use super::{de, from_seconds, ser, to_seconds, Deserialize, Deserializer, Interval};
+    use super::{Serialize, Serializer, LONG, SUBSECOND};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        if duration.subsec_nanos() != 0 {
+            return Err(ser::Error::custom(SUBSECOND));
+        }
+        let seconds = i64::try_from(duration.as_secs()).map_err(|_| ser::Error::custom(LONG))?;
+        from_seconds(seconds).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let seconds = to_seconds(&Interval::deserialize(deserializer)?)?;
+        let seconds = u64::try_from(seconds)
+            .map_err(|_| de::Error::custom("expected a duration that isn't negative"))?;
+        Ok(Duration::from_secs(seconds))
+    }
+}
+
+/// Read and write chrono's `TimeDelta`, also known as `chrono::Duration`
+#[cfg(feature = "chrono_0_4")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono_0_4")))]
+pub mod chrono_duration {
+    use super::{de, from_seconds, ser, to_seconds, Deserialize, Deserializer, Interval};
+    use super::{Serialize, Serializer, LONG, SUBSECOND};
+    use chrono::TimeDelta;
+
+    pub fn serialize<S: Serializer>(
+        duration: &TimeDelta,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        if duration.subsec_nanos() != 0 {
+            return Err(ser::Error::custom(SUBSECOND));
+        }
+        from_seconds(duration.num_seconds()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TimeDelta, D::Error> {
+        let seconds = to_seconds(&Interval::deserialize(deserializer)?)?;
+        TimeDelta::try_seconds(seconds).ok_or_else(|| de::Error::custom(LONG))
+    }
+}
+
+/// Read and write [`time::Duration`]s
+#[cfg(feature = "time_0_3")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time_0_3")))]
+pub mod time_duration {
+    use super::{from_seconds, ser, to_seconds, Deserialize, Deserializer, Interval};
+    use super::{Serialize, Serializer, SUBSECOND};
+    use time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        if duration.subsec_nanoseconds() != 0 {
+            return Err(ser::Error::custom(SUBSECOND));
+        }
+        from_seconds(duration.whole_seconds()).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        let seconds = to_seconds(&Interval::deserialize(deserializer)?)?;
+        Ok(Duration::seconds(seconds))
+    }
+}
+
+/// Read and write jiff's `Span`, keeping months and days as calendar units.
+///
+/// Only spans in a single group of units can be written: years and months, weeks and days,
+/// or hours, minutes and seconds.
+#[cfg(feature = "jiff_0_2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff_0_2")))]
+pub mod jiff_span {
+    use super::{de, ser, Deserialize, Deserializer, Interval, Serialize, Serializer};
+    use jiff_0_2::Span;
+
+    pub fn serialize<S: Serializer>(span: &Span, serializer: S) -> Result<S::Ok, S::Error> {
+        Interval::try_from(*span)
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Span, D::Error> {
+        Span::try_from(Interval::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+const SUBSECOND: &str = "expected a whole number of seconds";
+const LONG: &str = "the duration is too long";
+
+// reads a string with a parsing function
+struct Parse<F, T>(F, &'static str, PhantomData<T>);
+
+impl<F: FnOnce(&str) -> DateResult<T>, T> Visitor<'_> for Parse<F, T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.1)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        (self.0)(v).map_err(E::custom)
+    }
+}
+
+fn to_seconds<E: de::Error>(interval: &Interval) -> Result<i64, E> {
+    match *interval {
+        Interval::Seconds(seconds) => Ok(seconds),
+        Interval::Days(days) => days.checked_mul(86400).ok_or_else(|| E::custom(LONG)),
+        Interval::Months(_) => Err(E::custom(
+            "expected a duration of a fixed length, not months or years",
+        )),
+    }
+}
+
+// whole days are kept as days, so they're written as days or weeks
+fn from_seconds(seconds: i64) -> Interval {
+    if seconds % 86400 == 0 && seconds != 0 {
+        Interval::Days(seconds / 86400)
+    } else {
+        Interval::Seconds(seconds)
+    }
+}
@@ -1,7 +1,12 @@
-use core::ops::Mul;
+use core::fmt;
+use core::ops::{Mul, Neg};
+use core::str::FromStr;
+
+use logos::Span;
 
 use crate::datetime::{Date, DateTime, Time};
-use crate::Dialect;
+use crate::tz::Zone;
+use crate::{DateError, DateResult, Dialect, Disambiguation, Options};
 
 // implements next/last direction in expressions like 'next friday' and 'last 4 july'
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -36,10 +41,10 @@ impl ByName {
         self,
         base: Dt,
         ts: Option<TimeSpec>,
-        dialect: Dialect,
+        options: Options,
         mut direct: Direction,
-    ) -> Option<Dt> {
-        let (tz, base_date, base_time) = base.split();
+    ) -> DateResult<Dt> {
+        let (tz, base_date, base_time) = base.split().ok_or(DateError::MissingDate)?;
         let ts = ts.unwrap_or(TimeSpec::new(0, 0, 0, 0));
         let this_year = base_date.year();
         let date = match self {
@@ -51,47 +56,59 @@ impl ByName {
                 let mut extra_week = false;
                 match direct {
                     Direction::Here => direct = Direction::Next,
-                    Direction::Next if dialect == Dialect::Uk => {
+                    Direction::Next if options.dialect == Dialect::Uk => {
                         extra_week = true;
                     }
                     _ => (),
-                };
+                }
                 let this_day = base_date.weekday() as i64;
                 let that_day = nd as i64;
                 let diff_days = that_day - this_day;
-                let mut date = base_date.clone().offset_days(diff_days)?;
+                let mut date = base_date
+                    .clone()
+                    .offset_days(diff_days)
+                    .ok_or(DateError::MissingDate)?;
                 if let Some(correct) = next_last_direction(&date, &base_date, direct) {
-                    date = date.offset_days(7 * correct as i64)?;
+                    date = date
+                        .offset_days(7 * correct as i64)
+                        .ok_or(DateError::MissingDate)?;
                 }
                 if extra_week {
-                    date = date.offset_days(7)?;
+                    date = date.offset_days(7).ok_or(DateError::MissingDate)?;
                 }
                 if diff_days == 0 {
                     // same day - comparing times will determine which way we swing...
-                    let this_time = <Dt::Time as Time>::from_hms(ts.hour, ts.min, ts.sec)?;
+                    let this_time = <Dt::Time as Time>::from_hms(ts.hour, ts.min, ts.sec)
+                        .ok_or(DateError::MissingTime)?;
                     if let Some(correct) = next_last_direction(&this_time, &base_time, direct) {
-                        date = date.offset_days(7 * correct as i64)?;
+                        date = date
+                            .offset_days(7 * correct as i64)
+                            .ok_or(DateError::MissingDate)?;
                     }
                 }
                 date
             }
             ByName::MonthName(month) => {
-                let mut date = <Dt::Date as Date>::from_ymd(this_year, month as u8, 1)?;
+                let mut date = <Dt::Date as Date>::from_ymd(this_year, month as u8, 1)
+                    .ok_or(DateError::MissingDate)?;
                 if let Some(correct) = next_last_direction(&date, &base_date, direct) {
-                    date = <Dt::Date as Date>::from_ymd(this_year + correct, month as u8, 1)?;
+                    date = <Dt::Date as Date>::from_ymd(this_year + correct, month as u8, 1)
+                        .ok_or(DateError::MissingDate)?;
                 }
                 date
             }
             ByName::DayMonth { day, month } => {
-                let mut date = <Dt::Date as Date>::from_ymd(this_year, month as u8, day as u8)?;
+                let mut date = <Dt::Date as Date>::from_ymd(this_year, month as u8, day as u8)
+                    .ok_or(DateError::MissingDate)?;
                 if let Some(correct) = next_last_direction(&date, &base_date, direct) {
                     date =
-                        <Dt::Date as Date>::from_ymd(this_year + correct, month as u8, day as u8)?;
+                        <Dt::Date as Date>::from_ymd(this_year + correct, month as u8, day as u8)
+                            .ok_or(DateError::MissingDate)?;
                 }
                 date
             }
         };
-        ts.into_date_time(tz, date)
+        ts.into_date_time(tz, date, options.disambiguation)
     }
 }
 
@@ -108,6 +125,26 @@ impl AbsDate {
     }
 }
 
+// an exact instant, as seconds and microseconds since the unix epoch
+#[derive(Debug, Clone)]
+pub struct Timestamp {
+    pub secs: i64,
+    pub micros: u32,
+}
+
+impl Timestamp {
+    pub fn into_date_time<Dt: DateTime>(self, tz: Dt::TimeZone) -> DateResult<Dt> {
+        // construct the epoch in UTC through the backend, then move forward to the instant.
+        // this gives us the instant in the requested timezone
+        let epoch = <Dt::Date as Date>::from_ymd(1970, 1, 1).ok_or(DateError::MissingDate)?;
+        TimeSpec::new(0, 0, 0, self.micros)
+            .with_offset(0)
+            .into_date_time::<Dt>(tz, epoch, Disambiguation::Compatible)?
+            .offset_seconds(self.secs)
+            .ok_or(DateError::MissingDate)
+    }
+}
+
 /// A generic amount of time, in either seconds, days, or months.
 ///
 /// This way, a user can decide how they want to treat days (which do
@@ -121,49 +158,231 @@ impl AbsDate {
 // depending on whether this is a leap year.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Interval {
-    Seconds(i32),
-    Days(i32),
-    Months(i32),
+    Seconds(i64),
+    Days(i64),
+    Months(i64),
+}
+
+/// # Panics
+///
+/// If the result overflows. [`Interval::checked_mul`] gives `None` instead.
+impl Mul<i64> for Interval {
+    type Output = Interval;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("the interval should not overflow")
+    }
 }
 
-impl Mul<i32> for Interval {
+/// # Panics
+///
+/// If the result overflows. [`Interval::checked_neg`] gives `None` instead.
+impl Neg for Interval {
     type Output = Interval;
 
-    fn mul(self, rhs: i32) -> Self::Output {
-        match self {
-            Interval::Seconds(x) => Interval::Seconds(x * rhs),
-            Interval::Days(x) => Interval::Days(x * rhs),
-            Interval::Months(x) => Interval::Months(x * rhs),
+    fn neg(self) -> Self::Output {
+        self.checked_neg()
+            .expect("the interval should not overflow")
+    }
+}
+
+/// Writes the interval in the largest units it's a whole number of, like "3 hours", "2 weeks"
+/// or "1 year 6 months", and "3 days ago" if it's negative. The alternate form `{:#}` is
+/// compact, like "3h", "2w", "1y6mo" or "-3d". Both are read back by [`parse_duration`](crate::parse_duration).
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (n, units): (i64, &[(u64, &str, &str)]) = match *self {
+            Interval::Seconds(n) => (
+                n,
+                &[(3600, "hour", "h"), (60, "minute", "m"), (1, "second", "s")],
+            ),
+            Interval::Days(n) => (n, &[(7, "week", "w"), (1, "day", "d")]),
+            Interval::Months(n) => (n, &[(12, "year", "y"), (1, "month", "mo")]),
+        };
+
+        if f.alternate() && n < 0 {
+            f.write_str("-")?;
         }
+        let mut rest = n.unsigned_abs();
+        let mut first = true;
+        for &(size, name, short) in units {
+            let count = rest / size;
+            rest %= size;
+            // zero is written in the smallest unit, "0 seconds"
+            if count == 0 && !(first && size == 1) {
+                continue;
+            }
+            if f.alternate() {
+                write!(f, "{count}{short}")?;
+            } else {
+                let space = if first { "" } else { " " };
+                let plural = if count == 1 { "" } else { "s" };
+                write!(f, "{space}{count} {name}{plural}")?;
+            }
+            first = false;
+        }
+        if !f.alternate() && n < 0 {
+            f.write_str(" ago")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a duration with [`parse_duration`](crate::parse_duration)
+impl FromStr for Interval {
+    type Err = DateError;
+
+    fn from_str(s: &str) -> DateResult<Self> {
+        crate::parse_duration(s)
     }
 }
 
 impl Interval {
-    fn into_date_time<Dt: DateTime>(self, base: Dt, ts: Option<TimeSpec>) -> Option<Dt> {
+    /// The date-time this interval after `base`.
+    ///
+    /// Seconds are added to the instant, and days and months are added to the date, keeping the
+    /// time of day. So "1 day" is 23 or 25 hours across a change to or from daylight saving time.
+    /// Adding months keeps the day of the month if it exists, and otherwise gives the last day of
+    /// the month: 30 January plus 1 month is 28 February, or 29 February in a leap year.
+    /// A local time that's skipped or repeated is resolved like [`Disambiguation::Compatible`].
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use interim::Interval;
+    ///
+    /// let base = Utc.with_ymd_and_hms(2024, 1, 30, 9, 0, 0).unwrap();
+    /// assert_eq!(Interval::Months(1).add_to(base), Utc.with_ymd_and_hms(2024, 2, 29, 9, 0, 0).unwrap());
+    /// assert_eq!(Interval::Days(-30).add_to(base), Utc.with_ymd_and_hms(2023, 12, 31, 9, 0, 0).unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the result is out of range for `Dt`. [`Interval::checked_add_to`] gives `None` instead.
+    #[must_use]
+    pub fn add_to<Dt: DateTime>(&self, base: Dt) -> Dt {
+        self.checked_add_to(base)
+            .expect("the date-time should be in range")
+    }
+
+    /// The date-time this interval before `base`, which is `base` plus the negated interval.
+    ///
+    /// # Panics
+    ///
+    /// If the result is out of range for `Dt`, or the interval can't be negated.
+    /// [`Interval::checked_sub_from`] gives `None` instead.
+    #[must_use]
+    pub fn sub_from<Dt: DateTime>(&self, base: Dt) -> Dt {
+        self.checked_sub_from(base)
+            .expect("the date-time should be in range")
+    }
+
+    /// Like [`Interval::add_to`], or `None` if the result is out of range
+    #[must_use]
+    pub fn checked_add_to<Dt: DateTime>(&self, base: Dt) -> Option<Dt> {
+        self.clone()
+            .offset_from(base, Disambiguation::Compatible)
+            .ok()
+    }
+
+    /// Like [`Interval::sub_from`], or `None` if the result is out of range
+    #[must_use]
+    pub fn checked_sub_from<Dt: DateTime>(&self, base: Dt) -> Option<Dt> {
+        self.checked_neg()?.checked_add_to(base)
+    }
+
+    /// The sum of two intervals in the same unit, or `None` if the units are different or it
+    /// overflows. Days and months don't have a fixed length, so they can't be added to others.
+    #[must_use]
+    pub fn checked_add(&self, rhs: &Interval) -> Option<Interval> {
+        match (self, rhs) {
+            (Interval::Seconds(a), Interval::Seconds(b)) => {
+                a.checked_add(*b).map(Interval::Seconds)
+            }
+            (Interval::Days(a), Interval::Days(b)) => a.checked_add(*b).map(Interval::Days),
+            (Interval::Months(a), Interval::Months(b)) => a.checked_add(*b).map(Interval::Months),
+            _ => None,
+        }
+    }
+
+    /// The difference of two intervals in the same unit, like [`Interval::checked_add`]
+    #[must_use]
+    pub fn checked_sub(&self, rhs: &Interval) -> Option<Interval> {
+        self.checked_add(&rhs.checked_neg()?)
+    }
+
+    /// The interval multiplied by `rhs`, or `None` if it overflows
+    #[must_use]
+    pub fn checked_mul(&self, rhs: i64) -> Option<Interval> {
+        match *self {
+            Interval::Seconds(n) => n.checked_mul(rhs).map(Interval::Seconds),
+            Interval::Days(n) => n.checked_mul(rhs).map(Interval::Days),
+            Interval::Months(n) => n.checked_mul(rhs).map(Interval::Months),
+        }
+    }
+
+    /// The negated interval, or `None` if it overflows
+    #[must_use]
+    pub fn checked_neg(&self) -> Option<Interval> {
+        self.checked_mul(-1)
+    }
+
+    // the date-time this far from the base, at the same time of day for days and months
+    pub(crate) fn offset_from<Dt: DateTime>(
+        self,
+        base: Dt,
+        disambiguation: Disambiguation,
+    ) -> DateResult<Dt> {
         match self {
-            Interval::Seconds(secs) => {
-                // since numbers of seconds _is a timespec_, we don't add the timespec on top
-                // eg now + 15m shouldn't then process 12pm after it.
-                // Ideally Interval::Seconds should be part of timespec.
-                base.offset_seconds(secs as i64)
+            Interval::Seconds(secs) => base.offset_seconds(secs).ok_or(DateError::MissingDate),
+            Interval::Days(days) => {
+                let (tz, date, time) = base.split().ok_or(DateError::MissingDate)?;
+                let date = date.offset_days(days).ok_or(DateError::MissingDate)?;
+                Dt::new(tz, date, time, disambiguation)
+            }
+            Interval::Months(months) => {
+                let (tz, date, time) = base.split().ok_or(DateError::MissingDate)?;
+                let date = i32::try_from(months)
+                    .ok()
+                    .and_then(|months| date.offset_months(months))
+                    .ok_or(DateError::MissingDate)?;
+                Dt::new(tz, date, time, disambiguation)
             }
+        }
+    }
+
+    fn into_date_time<Dt: DateTime>(
+        self,
+        base: Dt,
+        ts: Option<TimeSpec>,
+        disambiguation: Disambiguation,
+    ) -> DateResult<Dt> {
+        match self {
+            // since numbers of seconds _is a timespec_, we don't add the timespec on top
+            // eg now + 15m shouldn't then process 12pm after it.
+            // Ideally Interval::Seconds should be part of timespec.
+            Interval::Seconds(_) => self.offset_from(base, disambiguation),
             Interval::Days(days) => {
-                let (tz, date, time) = base.split();
-                let date = date.offset_days(days as i64)?;
                 if let Some(ts) = ts {
-                    ts.into_date_time(tz, date)
+                    let (tz, date, _) = base.split().ok_or(DateError::MissingDate)?;
+                    let date = date.offset_days(days).ok_or(DateError::MissingDate)?;
+                    ts.into_date_time(tz, date, disambiguation)
                 } else {
-                    Some(Dt::new(tz, date, time))
+                    self.offset_from(base, disambiguation)
                 }
             }
             Interval::Months(months) => {
-                let (tz, date, _) = base.split();
-                let date = date.offset_months(months)?;
+                let (tz, date, _) = base.split().ok_or(DateError::MissingDate)?;
+                let date = i32::try_from(months)
+                    .ok()
+                    .and_then(|months| date.offset_months(months))
+                    .ok_or(DateError::MissingDate)?;
                 if let Some(ts) = ts {
-                    ts.into_date_time(tz, date)
+                    ts.into_date_time(tz, date, disambiguation)
                 } else {
-                    let time = <Dt::Time as Time>::from_hms(0, 0, 0)?;
-                    Some(Dt::new(tz, date, time))
+                    let time =
+                        <Dt::Time as Time>::from_hms(0, 0, 0).ok_or(DateError::MissingTime)?;
+                    Dt::new(tz, date, time, disambiguation)
                 }
             }
         }
@@ -175,6 +394,7 @@ pub enum DateSpec {
     Absolute(AbsDate),           // Y M D (e.g. 2018-06-02, 4 July 2017)
     Relative(Interval),          // n U (e.g. 2min, 3 years ago, -2d)
     FromName(ByName, Direction), // (e.g. 'next fri', 'jul')
+    Timestamp(Timestamp),        // @N (e.g. @1700000000, @1700000000.123)
 }
 
 impl DateSpec {
@@ -182,19 +402,28 @@ impl DateSpec {
         self,
         base: Dt,
         ts: Option<TimeSpec>,
-        dialect: Dialect,
-    ) -> Option<Dt> {
+        options: Options,
+    ) -> DateResult<Dt> {
         match self {
-            DateSpec::Absolute(ad) => match ts {
-                Some(ts) => ts.into_date_time(base.split().0, ad.into_date()?),
-                None => Some(Dt::new(
-                    base.split().0,
-                    ad.into_date::<Dt::Date>()?,
-                    <Dt::Time>::from_hms(0, 0, 0)?,
-                )),
-            },
-            DateSpec::Relative(skip) => skip.into_date_time(base, ts),
-            DateSpec::FromName(byname, direct) => byname.into_date_time(base, ts, dialect, direct),
+            DateSpec::Absolute(ad) => {
+                let date = ad.into_date().ok_or(DateError::MissingDate)?;
+                let (tz, _, _) = base.split().ok_or(DateError::MissingDate)?;
+                match ts {
+                    Some(ts) => ts.into_date_time(tz, date, options.disambiguation),
+                    None => Dt::new(
+                        tz,
+                        date,
+                        <Dt::Time>::from_hms(0, 0, 0).ok_or(DateError::MissingTime)?,
+                        options.disambiguation,
+                    ),
+                }
+            }
+            DateSpec::Relative(skip) => skip.into_date_time(base, ts, options.disambiguation),
+            DateSpec::FromName(byname, direct) => byname.into_date_time(base, ts, options, direct),
+            DateSpec::Timestamp(timestamp) => {
+                let (tz, _, _) = base.split().ok_or(DateError::MissingDate)?;
+                timestamp.into_date_time(tz)
+            }
         }
     }
 }
@@ -205,7 +434,7 @@ pub struct TimeSpec {
     pub min: u32,
     pub sec: u32,
     pub microsec: u32,
-    pub offset: Option<i64>,
+    pub zone: Option<Zone>,
 }
 
 impl TimeSpec {
@@ -215,23 +444,37 @@ impl TimeSpec {
             min,
             sec,
             microsec,
-            offset: None,
+            zone: None,
         }
     }
 
-    pub fn with_offset(mut self, offset: i64) -> Self {
-        self.offset = Some(offset);
+    pub fn with_offset(self, offset: i64) -> Self {
+        self.with_zone(Zone::Fixed(offset))
+    }
+
+    pub fn with_zone(mut self, zone: Zone) -> Self {
+        self.zone = Some(zone);
         self
     }
 
-    pub fn into_date_time<Dt: DateTime>(self, tz: Dt::TimeZone, date: Dt::Date) -> Option<Dt> {
-        let date = date.offset_days((self.hour / 24) as i64)?;
-        let time = <Dt::Time as Time>::from_hms(self.hour % 24, self.min, self.sec)?
-            .with_micros(self.microsec)?;
-        if let Some(offs) = self.offset {
-            Dt::new(tz, date, time).with_offset(offs)
+    pub fn into_date_time<Dt: DateTime>(
+        self,
+        tz: Dt::TimeZone,
+        date: Dt::Date,
+        disambiguation: Disambiguation,
+    ) -> DateResult<Dt> {
+        let date = date
+            .offset_days((self.hour / 24) as i64)
+            .ok_or(DateError::MissingDate)?;
+        let time = <Dt::Time as Time>::from_hms(self.hour % 24, self.min, self.sec)
+            .and_then(|time| time.with_micros(self.microsec))
+            .ok_or(DateError::MissingTime)?;
+        if let Some(zone) = self.zone {
+            let offs =
+                zone.offset_for_local(&date, self.hour % 24, self.min, self.sec, disambiguation)?;
+            Dt::from_offset(tz, date, time, offs)
         } else {
-            Some(Dt::new(tz, date, time))
+            Dt::new(tz, date, time, disambiguation)
         }
     }
 }
@@ -240,6 +483,19 @@ impl TimeSpec {
 pub struct DateTimeSpec {
     pub date: Option<DateSpec>,
     pub time: Option<TimeSpec>,
+    // the timezone to convert the result into
+    pub convert: Option<Zone>,
+    pub spans: Spans,
+}
+
+// where each part of a DateTimeSpec was found in the text
+#[derive(Debug, Clone, Default)]
+pub struct Spans {
+    pub date: Span,
+    pub time: Span,
+    // the timezone of the time
+    pub zone: Span,
+    pub convert: Span,
 }
 
 #[derive(PartialEq, Eq, Clone, Copy)]
@@ -0,0 +1,312 @@
+use crate::datetime::Date;
+use crate::types::Lowercase;
+#[cfg(any(feature = "jiff_0_2", feature = "chrono_0_4", feature = "time_0_3"))]
+use crate::DateError;
+use crate::{DateResult, Dialect, Disambiguation};
+
+/// A timezone given in the input text
+#[derive(Debug, Clone)]
+pub enum Zone {
+    /// offset east of UTC, in seconds
+    Fixed(i64),
+    #[cfg(feature = "jiff_0_2")]
+    Jiff(jiff_0_2::tz::TimeZone),
+    #[cfg(feature = "chrono_tz_0_10")]
+    ChronoTz(chrono_tz::Tz),
+}
+
+impl Zone {
+    /// look up an IANA timezone name, like 'Europe/London'
+    pub fn named(name: &str) -> Option<Self> {
+        #[cfg(feature = "jiff_0_2")]
+        if let Ok(tz) = jiff_0_2::tz::TimeZone::get(name) {
+            return Some(Zone::Jiff(tz));
+        }
+        #[cfg(feature = "chrono_tz_0_10")]
+        if let Ok(tz) = name.parse::<chrono_tz::Tz>() {
+            return Some(Zone::ChronoTz(tz));
+        }
+        let _ = name;
+        None
+    }
+
+    /// The IANA name of this zone, if it has one
+    pub fn iana_name(&self) -> Option<&str> {
+        match self {
+            Zone::Fixed(_) => None,
+            #[cfg(feature = "jiff_0_2")]
+            Zone::Jiff(tz) => tz.iana_name(),
+            #[cfg(feature = "chrono_tz_0_10")]
+            Zone::ChronoTz(tz) => Some(tz.name()),
+        }
+    }
+
+    /// The offset from UTC in seconds, in effect at the instant given as seconds since the unix epoch
+    #[cfg_attr(
+        not(any(feature = "jiff_0_2", feature = "chrono_tz_0_10")),
+        allow(unused_variables, clippy::unnecessary_wraps)
+    )]
+    pub fn offset_at(&self, timestamp: i64) -> Option<i64> {
+        match self {
+            Zone::Fixed(offset) => Some(*offset),
+            #[cfg(feature = "jiff_0_2")]
+            Zone::Jiff(tz) => {
+                let timestamp = jiff_0_2::Timestamp::from_second(timestamp).ok()?;
+                Some(tz.to_offset(timestamp).seconds() as i64)
+            }
+            #[cfg(feature = "chrono_tz_0_10")]
+            Zone::ChronoTz(tz) => {
+                use chrono::{Offset, TimeZone};
+
+                let datetime = chrono::DateTime::from_timestamp(timestamp, 0)?;
+                let offset = tz.offset_from_utc_datetime(&datetime.naive_utc());
+                Some(offset.fix().local_minus_utc() as i64)
+            }
+        }
+    }
+
+    /// The offset from UTC in seconds, in effect at the local date and time in this zone.
+    ///
+    /// Times that are skipped or repeated by a DST transition are resolved with `disambiguation`.
+    #[cfg_attr(
+        not(any(feature = "jiff_0_2", feature = "chrono_tz_0_10")),
+        allow(unused_variables, clippy::unnecessary_wraps)
+    )]
+    pub fn offset_for_local<D: Date>(
+        &self,
+        date: &D,
+        hour: u32,
+        min: u32,
+        sec: u32,
+        disambiguation: Disambiguation,
+    ) -> DateResult<i64> {
+        match self {
+            Zone::Fixed(offset) => Ok(*offset),
+            #[cfg(feature = "jiff_0_2")]
+            Zone::Jiff(tz) => {
+                use jiff_0_2::tz::AmbiguousOffset;
+
+                let datetime = jiff_0_2::civil::DateTime::new(
+                    date.year().try_into().map_err(|_| DateError::MissingDate)?,
+                    date.month() as i8,
+                    date.day() as i8,
+                    hour as i8,
+                    min as i8,
+                    sec as i8,
+                    0,
+                )
+                .map_err(|_| DateError::MissingTime)?;
+                match tz.to_ambiguous_timestamp(datetime).offset() {
+                    AmbiguousOffset::Unambiguous { offset } => Ok(offset.seconds() as i64),
+                    AmbiguousOffset::Gap { before, after } => resolve_gap(
+                        before.seconds() as i64,
+                        after.seconds() as i64,
+                        disambiguation,
+                    ),
+                    AmbiguousOffset::Fold { before, after } => resolve_fold(
+                        before.seconds() as i64,
+                        after.seconds() as i64,
+                        disambiguation,
+                    ),
+                }
+            }
+            #[cfg(feature = "chrono_tz_0_10")]
+            Zone::ChronoTz(tz) => {
+                use chrono::NaiveDate;
+
+                let datetime =
+                    NaiveDate::from_ymd_opt(date.year(), date.month() as u32, date.day() as u32)
+                        .ok_or(DateError::MissingDate)?
+                        .and_hms_opt(hour, min, sec)
+                        .ok_or(DateError::MissingTime)?;
+                let resolved = crate::datetime::chrono::from_local(tz, datetime, disambiguation)?;
+                // in a gap, the resolved time has moved, so this isn't the offset in effect
+                Ok((datetime - resolved.naive_utc()).num_seconds())
+            }
+        }
+    }
+}
+
+// pick between the two offsets either side of a local time skipped by a DST transition.
+// the offset before the gap puts the time after the gap, and vice versa
+#[cfg(any(feature = "jiff_0_2", feature = "chrono_0_4", feature = "time_0_3"))]
+pub(crate) fn resolve_gap<T>(before: T, after: T, disambiguation: Disambiguation) -> DateResult<T> {
+    match disambiguation {
+        Disambiguation::Compatible | Disambiguation::Later => Ok(before),
+        Disambiguation::Earlier => Ok(after),
+        Disambiguation::Reject => Err(DateError::NonexistentTime),
+    }
+}
+
+// pick between the two occurrences of a local time repeated by a DST transition
+#[cfg(any(feature = "jiff_0_2", feature = "chrono_0_4", feature = "time_0_3"))]
+pub(crate) fn resolve_fold<T>(
+    earlier: T,
+    later: T,
+    disambiguation: Disambiguation,
+) -> DateResult<T> {
+    match disambiguation {
+        Disambiguation::Compatible | Disambiguation::Earlier => Ok(earlier),
+        Disambiguation::Later => Ok(later),
+        Disambiguation::Reject => Err(DateError::AmbiguousTime),
+    }
+}
+
+// the IANA timezone for some common city names
+const CITIES: &[(&str, &str)] = &[
+    // europe
+    ("london", "Europe/London"),
+    ("dublin", "Europe/Dublin"),
+    ("lisbon", "Europe/Lisbon"),
+    ("paris", "Europe/Paris"),
+    ("brussels", "Europe/Brussels"),
+    ("amsterdam", "Europe/Amsterdam"),
+    ("berlin", "Europe/Berlin"),
+    ("madrid", "Europe/Madrid"),
+    ("rome", "Europe/Rome"),
+    ("zurich", "Europe/Zurich"),
+    ("vienna", "Europe/Vienna"),
+    ("prague", "Europe/Prague"),
+    ("warsaw", "Europe/Warsaw"),
+    ("copenhagen", "Europe/Copenhagen"),
+    ("oslo", "Europe/Oslo"),
+    ("stockholm", "Europe/Stockholm"),
+    ("helsinki", "Europe/Helsinki"),
+    ("athens", "Europe/Athens"),
+    ("istanbul", "Europe/Istanbul"),
+    ("kyiv", "Europe/Kyiv"),
+    ("moscow", "Europe/Moscow"),
+    // africa and the middle east
+    ("cairo", "Africa/Cairo"),
+    ("lagos", "Africa/Lagos"),
+    ("nairobi", "Africa/Nairobi"),
+    ("johannesburg", "Africa/Johannesburg"),
+    ("tel aviv", "Asia/Jerusalem"),
+    ("jerusalem", "Asia/Jerusalem"),
+    ("dubai", "Asia/Dubai"),
+    // asia
+    ("karachi", "Asia/Karachi"),
+    ("delhi", "Asia/Kolkata"),
+    ("new delhi", "Asia/Kolkata"),
+    ("mumbai", "Asia/Kolkata"),
+    ("bangalore", "Asia/Kolkata"),
+    ("kolkata", "Asia/Kolkata"),
+    ("bangkok", "Asia/Bangkok"),
+    ("jakarta", "Asia/Jakarta"),
+    ("singapore", "Asia/Singapore"),
+    ("hong kong", "Asia/Hong_Kong"),
+    ("beijing", "Asia/Shanghai"),
+    ("shanghai", "Asia/Shanghai"),
+    ("taipei", "Asia/Taipei"),
+    ("manila", "Asia/Manila"),
+    ("seoul", "Asia/Seoul"),
+    ("tokyo", "Asia/Tokyo"),
+    // oceania
+    ("perth", "Australia/Perth"),
+    ("adelaide", "Australia/Adelaide"),
+    ("brisbane", "Australia/Brisbane"),
+    ("sydney", "Australia/Sydney"),
+    ("melbourne", "Australia/Melbourne"),
+    ("auckland", "Pacific/Auckland"),
+    ("honolulu", "Pacific/Honolulu"),
+    // americas
+    ("anchorage", "America/Anchorage"),
+    ("vancouver", "America/Vancouver"),
+    ("seattle", "America/Los_Angeles"),
+    ("san francisco", "America/Los_Angeles"),
+    ("los angeles", "America/Los_Angeles"),
+    ("phoenix", "America/Phoenix"),
+    ("denver", "America/Denver"),
+    ("mexico city", "America/Mexico_City"),
+    ("chicago", "America/Chicago"),
+    ("toronto", "America/Toronto"),
+    ("new york", "America/New_York"),
+    ("boston", "America/New_York"),
+    ("washington", "America/New_York"),
+    ("bogota", "America/Bogota"),
+    ("lima", "America/Lima"),
+    ("santiago", "America/Santiago"),
+    ("buenos aires", "America/Argentina/Buenos_Aires"),
+    ("sao paulo", "America/Sao_Paulo"),
+];
+
+/// look up the IANA timezone name for a city, written as one or two words
+pub(crate) fn city(first: &str, second: Option<&str>) -> Option<&'static str> {
+    CITIES.iter().find_map(|&(city, tz)| {
+        let matches = match (city.split_once(' '), second) {
+            (Some((a, b)), Some(second)) => {
+                a.eq_ignore_ascii_case(first) && b.eq_ignore_ascii_case(second)
+            }
+            (None, None) => city.eq_ignore_ascii_case(first),
+            _ => false,
+        };
+        matches.then_some(tz)
+    })
+}
+
+// offsets in minutes east of UTC, for the timezone abbreviations that are unambiguous
+const TIMEZONES: &[(Lowercase, i32)] = &[
+    // europe and africa
+    (Lowercase::literal("wet"), 0),
+    (Lowercase::literal("west"), 60),
+    (Lowercase::literal("bst"), 60),
+    (Lowercase::literal("cet"), 60),
+    (Lowercase::literal("cest"), 2 * 60),
+    (Lowercase::literal("eet"), 2 * 60),
+    (Lowercase::literal("eest"), 3 * 60),
+    (Lowercase::literal("msk"), 3 * 60),
+    (Lowercase::literal("wat"), 60),
+    (Lowercase::literal("cat"), 2 * 60),
+    (Lowercase::literal("sast"), 2 * 60),
+    (Lowercase::literal("eat"), 3 * 60),
+    // north america
+    (Lowercase::literal("nst"), -(3 * 60 + 30)),
+    (Lowercase::literal("ndt"), -(2 * 60 + 30)),
+    (Lowercase::literal("ast"), -4 * 60),
+    (Lowercase::literal("adt"), -3 * 60),
+    (Lowercase::literal("est"), -5 * 60),
+    (Lowercase::literal("edt"), -4 * 60),
+    (Lowercase::literal("cdt"), -5 * 60),
+    (Lowercase::literal("mst"), -7 * 60),
+    (Lowercase::literal("mdt"), -6 * 60),
+    (Lowercase::literal("pst"), -8 * 60),
+    (Lowercase::literal("pdt"), -7 * 60),
+    (Lowercase::literal("akst"), -9 * 60),
+    (Lowercase::literal("akdt"), -8 * 60),
+    (Lowercase::literal("hst"), -10 * 60),
+    // asia and oceania
+    (Lowercase::literal("pkt"), 5 * 60),
+    (Lowercase::literal("ict"), 7 * 60),
+    (Lowercase::literal("wib"), 7 * 60),
+    (Lowercase::literal("hkt"), 8 * 60),
+    (Lowercase::literal("sgt"), 8 * 60),
+    (Lowercase::literal("awst"), 8 * 60),
+    (Lowercase::literal("jst"), 9 * 60),
+    (Lowercase::literal("kst"), 9 * 60),
+    (Lowercase::literal("acst"), 9 * 60 + 30),
+    (Lowercase::literal("acdt"), 10 * 60 + 30),
+    (Lowercase::literal("aest"), 10 * 60),
+    (Lowercase::literal("aedt"), 11 * 60),
+    (Lowercase::literal("nzst"), 12 * 60),
+    (Lowercase::literal("nzdt"), 13 * 60),
+];
+
+// timezone abbreviations as an offset in seconds.
+// Some abbreviations are ambiguous, so we pick the most likely meaning for the dialect
+pub(crate) fn timezone_abbreviation(s: Lowercase, dialect: Dialect) -> Option<i64> {
+    const CST: Lowercase = Lowercase::literal("cst");
+    const IST: Lowercase = Lowercase::literal("ist");
+
+    let minutes = match (s, dialect) {
+        // US Central Standard Time
+        (CST, Dialect::Us) => -6 * 60,
+        // China Standard Time
+        (CST, Dialect::Uk) => 8 * 60,
+        // India Standard Time
+        (IST, Dialect::Us) => 5 * 60 + 30,
+        // Irish Standard Time
+        (IST, Dialect::Uk) => 60,
+        _ => TIMEZONES.iter().find(|(name, _)| *name == s)?.1,
+    };
+    Some(i64::from(minutes) * 60)
+}
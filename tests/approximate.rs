@@ -0,0 +1,106 @@
+mod common;
+
+use common::at;
+use interim::{difference, ApproximateOptions, Interval};
+
+#[test]
+fn thresholds() {
+    let assert = |interval: Interval, expected: &str| {
+        assert_eq!(interval.approximate().to_string(), expected);
+    };
+
+    assert(Interval::Seconds(0), "a few seconds");
+    assert(Interval::Seconds(44), "a few seconds");
+    assert(Interval::Seconds(45), "a minute");
+    assert(Interval::Seconds(89), "a minute");
+    assert(Interval::Seconds(90), "2 minutes");
+    assert(Interval::Seconds(44 * 60), "44 minutes");
+    assert(Interval::Seconds(45 * 60), "about an hour");
+    assert(Interval::Seconds(89 * 60), "about an hour");
+    assert(Interval::Seconds(90 * 60), "about 2 hours");
+    assert(Interval::Seconds(21 * 3600), "about 21 hours");
+    assert(Interval::Seconds(26 * 3600), "a day");
+    assert(Interval::Seconds(36 * 3600), "2 days");
+    assert(Interval::Days(25), "25 days");
+    assert(Interval::Days(26), "about a month");
+    assert(Interval::Days(45), "about a month");
+    assert(Interval::Days(46), "about 2 months");
+    assert(Interval::Months(10), "about 10 months");
+    assert(Interval::Months(11), "about a year");
+    assert(Interval::Months(17), "about a year");
+    assert(Interval::Months(18), "about 2 years");
+    assert(Interval::Months(i64::MAX), "about 768614336404564651 years");
+
+    assert(Interval::Seconds(-10), "a few seconds ago");
+    assert(Interval::Seconds(-3600), "about an hour ago");
+    assert(Interval::Days(-3), "3 days ago");
+}
+
+#[test]
+fn options() {
+    let assert = |interval: Interval, options, expected: &str| {
+        assert_eq!(interval.approximate_with(options).to_string(), expected);
+    };
+    let options = ApproximateOptions::new();
+
+    assert(Interval::Seconds(30), options.few_seconds(10), "a minute");
+    // shorter than a minute without "a few seconds"
+    let seconds = options.few_seconds(0);
+    assert(Interval::Seconds(0), seconds, "0 seconds");
+    assert(Interval::Days(0), seconds, "0 seconds");
+    assert(Interval::Seconds(1), seconds, "a second");
+    assert(Interval::Seconds(20), seconds, "20 seconds");
+    assert(Interval::Seconds(-20), seconds, "20 seconds ago");
+    assert(Interval::Seconds(30), seconds, "a minute");
+    assert(Interval::Seconds(20), seconds.two_units(true), "20 seconds");
+    assert(Interval::Seconds(60), options.minutes(0), "about an hour");
+    assert(
+        Interval::Seconds(45 * 60),
+        options.minutes(60),
+        "45 minutes",
+    );
+    assert(Interval::Days(30), options.days(31), "30 days");
+    assert(Interval::Months(12), options.months(13), "about 12 months");
+    assert(
+        Interval::Seconds(30 * 3600),
+        options.hours(48),
+        "about 30 hours",
+    );
+
+    let two = options.two_units(true);
+    assert(Interval::Seconds(30), two, "a few seconds");
+    assert(Interval::Seconds(45), two, "a minute");
+    assert(Interval::Seconds(90), two, "1 minute 30 seconds");
+    assert(Interval::Seconds(90 * 60), two, "about 1 hour 30 minutes");
+    assert(Interval::Seconds(26 * 3600), two, "1 day 2 hours");
+    assert(Interval::Seconds(-26 * 3600), two, "1 day 2 hours ago");
+    assert(Interval::Days(2), two, "2 days");
+    // the second unit rounds up into the first
+    assert(
+        Interval::Seconds(2 * 86400 + 23 * 3600 + 40 * 60),
+        two,
+        "3 days",
+    );
+    assert(Interval::Months(14), two, "about 1 year 2 months");
+    assert(Interval::Days(400), two, "about 1 year 1 month");
+}
+
+#[test]
+fn differences() {
+    let start = at(2024, 1, 1, 9, 0, 0);
+    let end = at(2024, 3, 18, 21, 40, 30);
+    let two = ApproximateOptions::new().two_units(true);
+
+    let forward = difference(&start, &end).unwrap();
+    assert_eq!(forward.approximate().to_string(), "about 3 months");
+    assert_eq!(
+        forward.approximate_with(two).to_string(),
+        "about 2 months 18 days"
+    );
+    let back = difference(&end, &start).unwrap();
+    assert_eq!(back.approximate().to_string(), "about 3 months ago");
+
+    let end = at(2024, 1, 1, 9, 45, 0);
+    let difference = difference(&start, &end).unwrap();
+    assert_eq!(difference.approximate().to_string(), "about an hour");
+}
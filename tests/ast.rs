@@ -0,0 +1,151 @@
+use interim::ast::{DateKind, Direction, Name};
+use interim::{Dialect, Expression, Interval, Options};
+
+fn slice(text: &str, span: std::ops::Range<usize>) -> &str {
+    &text[span]
+}
+
+#[test]
+fn dates() {
+    let date = |text: &str, dialect: Dialect| {
+        let expression = Expression::parse(text, dialect).unwrap();
+        let date = expression.ast().date().unwrap();
+        (date.kind(), slice(text, date.span()).to_owned())
+    };
+
+    assert_eq!(
+        date("2017-06-30 08:20", Dialect::Uk),
+        (
+            DateKind::Absolute {
+                year: 2017,
+                month: 6,
+                day: 30
+            },
+            "2017-06-30".to_owned()
+        )
+    );
+    assert_eq!(
+        date("June   30,    2018", Dialect::Uk).1,
+        "June   30,    2018"
+    );
+    assert_eq!(
+        date("3 hours ago", Dialect::Uk),
+        (
+            DateKind::Relative(Interval::Seconds(-3 * 3600)),
+            "3 hours ago".to_owned()
+        )
+    );
+    assert_eq!(
+        date("tomorrow noon", Dialect::Uk),
+        (DateKind::Relative(Interval::Days(1)), "tomorrow".to_owned())
+    );
+    assert_eq!(
+        date("2d 03:00", Dialect::Uk),
+        (DateKind::Relative(Interval::Days(2)), "2d".to_owned())
+    );
+    assert_eq!(
+        date("last 8/11 9am", Dialect::Us),
+        (
+            DateKind::Named {
+                name: Name::DayMonth { day: 11, month: 8 },
+                direction: Direction::Last
+            },
+            "last 8/11".to_owned()
+        )
+    );
+    assert_eq!(
+        date("jul", Dialect::Uk).0,
+        DateKind::Named {
+            name: Name::Month(7),
+            direction: Direction::This
+        }
+    );
+    assert_eq!(
+        date("@-1.25", Dialect::Uk),
+        (
+            DateKind::Timestamp {
+                seconds: -2,
+                microseconds: 750_000
+            },
+            "@-1.25".to_owned()
+        )
+    );
+
+    let bare = Options::new(Dialect::Uk).bare_timestamps(true);
+    let expression = Expression::parse("1700000000", bare).unwrap();
+    assert!(!expression.ast().date().unwrap().is_relative());
+    let expression = Expression::parse("friday", bare).unwrap();
+    assert!(expression.ast().date().unwrap().is_relative());
+}
+
+#[test]
+fn times() {
+    let text = "friday 10:30:15.25 +05:30";
+    let expression = Expression::parse(text, Dialect::Uk).unwrap();
+    let time = expression.ast().time().unwrap();
+    assert_eq!(
+        (
+            time.hour(),
+            time.minute(),
+            time.second(),
+            time.microsecond()
+        ),
+        (10, 30, 15, 250_000)
+    );
+    assert_eq!(slice(text, time.span()), "10:30:15.25");
+    let zone = time.zone().unwrap();
+    assert_eq!(zone.fixed_offset(), Some(5 * 3600 + 30 * 60));
+    assert_eq!(zone.iana_name(), None);
+    assert_eq!(slice(text, zone.span()), "+05:30");
+
+    for (text, span) in [
+        ("12am", "12am"),
+        ("7:26 PM", "7:26 PM"),
+        ("noon", "noon"),
+        ("2017-06-30T08:20:30Z", "08:20:30"),
+        ("2017-06-30 8.30pm", "8.30pm"),
+    ] {
+        let expression = Expression::parse(text, Dialect::Uk).unwrap();
+        let time = expression.ast().time().unwrap();
+        assert_eq!(slice(text, time.span()), span, "{text:?}");
+    }
+
+    let expression = Expression::parse("3 weeks", Dialect::Uk).unwrap();
+    assert!(expression.ast().time().is_none());
+    let expression = Expression::parse("9am", Dialect::Uk).unwrap();
+    assert!(expression.ast().date().is_none());
+    assert!(expression.ast().time().unwrap().zone().is_none());
+}
+
+#[test]
+fn zones() {
+    let text = "9am in CET to UTC+1";
+    let expression = Expression::parse(text, Dialect::Uk).unwrap();
+    let ast = expression.ast();
+    let zone = ast.time().unwrap().zone().unwrap();
+    assert_eq!(slice(text, zone.span()), "CET");
+    assert_eq!(zone.fixed_offset(), Some(3600));
+    let convert = ast.convert().unwrap();
+    assert_eq!(slice(text, convert.span()), "UTC+1");
+    assert_eq!(convert.fixed_offset(), Some(3600));
+
+    let text = "now in -0130";
+    let expression = Expression::parse(text, Dialect::Uk).unwrap();
+    let convert = expression.ast().convert().unwrap();
+    assert_eq!(slice(text, convert.span()), "-0130");
+    assert_eq!(convert.fixed_offset(), Some(-5400));
+
+    #[cfg(any(feature = "jiff_0_2", feature = "chrono_tz_0_10"))]
+    {
+        let text = "friday 3pm America/New_York in new york";
+        let expression = Expression::parse(text, Dialect::Us).unwrap();
+        let ast = expression.ast();
+        let zone = ast.time().unwrap().zone().unwrap();
+        assert_eq!(slice(text, zone.span()), "America/New_York");
+        assert_eq!(zone.iana_name(), Some("America/New_York"));
+        assert_eq!(zone.fixed_offset(), None);
+        let convert = ast.convert().unwrap();
+        assert_eq!(slice(text, convert.span()), "new york");
+        assert_eq!(convert.iana_name(), Some("America/New_York"));
+    }
+}
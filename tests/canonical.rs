@@ -0,0 +1,140 @@
+use interim::civil::DateTime;
+use interim::{
+    canonicalize, canonicalize_with, parse_date_string_with, DateError, Dialect, Options,
+};
+use proptest::prelude::*;
+
+#[test]
+fn forms() {
+    let assert = |text: &str, dialect: Dialect, expected: &str| {
+        let canonical = canonicalize(text, dialect).map(|c| c.to_string());
+        assert_eq!(canonical.as_deref(), Ok(expected), "{text}");
+    };
+    let uk = Dialect::Uk;
+    let us = Dialect::Us;
+
+    assert("next fri 8 PM", uk, "next friday 20:00");
+    assert("Friday 20.00", uk, "friday 20:00");
+    assert("this fri at 8pm", us, "friday 20:00");
+    assert("last july", uk, "last july");
+    assert("july at 9am", uk, "july at 09:00");
+    assert("on 4 July", uk, "july 4");
+    assert("next 4 july at 9.30pm", uk, "next july 4 21:30");
+    assert("8/11", uk, "november 8");
+    assert("8/11", us, "august 11");
+    assert("8/11/22", uk, "2022-11-08");
+    assert("June 30, 2018 8:20:15.25am", us, "2018-06-30 08:20:15.25");
+    assert("2018", uk, "2018-01-01");
+
+    assert("now", uk, "now");
+    assert("today noon", uk, "today 12:00");
+    assert("yesterday at midnight", uk, "yesterday 00:00");
+    assert("tomorrow", uk, "tomorrow");
+    assert("- 3 days", uk, "3 days ago");
+    assert("in 2 weeks", uk, "2 weeks");
+    assert("next week", uk, "1 week");
+    assert("36 hours 9:00", uk, "36 hours 09:00");
+    assert("1 year 14 months ago", uk, "2 years 2 months ago");
+
+    assert("@1700000000.5", uk, "@1700000000.5");
+    assert("@-1.25", uk, "@-1.25");
+    assert("@-3.000001", uk, "@-3.000001");
+    assert("9am +0530", uk, "09:00 +05:30");
+    assert("9am UTC to -08:00", uk, "09:00 +00:00 to -08:00");
+    assert("3 days ago in Z", uk, "3 days ago to +00:00");
+    assert("@0 to UTC", uk, "@0 to +00:00");
+
+    let options = Options::new(uk).bare_timestamps(true);
+    let canonical = canonicalize_with("1700000000000", options).unwrap();
+    assert_eq!(canonical.to_string(), "@1700000000");
+
+    assert_eq!(
+        canonicalize("1/13", uk).unwrap_err(),
+        DateError::MissingDate
+    );
+    assert!(canonicalize("nxt fri", uk).is_err());
+}
+
+#[cfg(feature = "chrono_tz_0_10")]
+#[test]
+fn zones() {
+    let canonical = |text| canonicalize(text, Dialect::Uk).unwrap().to_string();
+    assert_eq!(
+        canonical("9am Europe/London to Asia/Tokyo"),
+        "09:00 Europe/London to Asia/Tokyo"
+    );
+    assert_eq!(canonical("friday 9am in tokyo"), "friday 09:00 Asia/Tokyo");
+}
+
+fn text() -> impl Strategy<Value = String> {
+    let date = prop::sample::select(vec![
+        "",
+        "today",
+        "yesterday",
+        "fri",
+        "next Monday",
+        "last sunday",
+        "july",
+        "last july",
+        "4 july",
+        "july 4",
+        "next 29 feb",
+        "8/11",
+        "3/4/22",
+        "2024-02-29",
+        "June 30, 2018",
+        "3 days ago",
+        "in 1 hour 30 minutes",
+        "2 years 3 months",
+        "next week",
+    ]);
+    let time = prop::sample::select(vec![
+        "",
+        "9am",
+        "12pm",
+        "at noon",
+        "midnight",
+        "17:05",
+        "8.30pm",
+        "23:59:59.5",
+    ]);
+    let zone = prop::sample::select(vec!["", "+05:30", "-0800", "UTC", "EST"]);
+    let convert = prop::sample::select(vec!["", "to UTC", "in -03:00"]);
+    (date, time, zone, convert).prop_map(|(date, time, zone, convert)| {
+        let zone = if time.is_empty() { "" } else { zone };
+        [date, time, zone, convert]
+            .into_iter()
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    })
+}
+
+proptest! {
+    #[test]
+    fn round_trip(
+        text in text(),
+        now in -2_000_000_000..2_000_000_000_i64,
+        us in any::<bool>(),
+    ) {
+        let dialect = if us { Dialect::Us } else { Dialect::Uk };
+        let Ok(canonical) = canonicalize(&text, dialect) else {
+            return Ok(());
+        };
+        let canonical = canonical.to_string();
+        // the canonical form reads the same in either dialect
+        for other in [Dialect::Uk, Dialect::Us] {
+            prop_assert_eq!(
+                canonicalize(&canonical, other).map(|c| c.to_string()),
+                Ok(canonical.clone())
+            );
+        }
+        let now = DateTime::from_unix(now, 0, 3600).unwrap();
+        let options = Options::new(dialect);
+        prop_assert_eq!(
+            parse_date_string_with(&text, now, options).ok(),
+            parse_date_string_with(&canonical, now, options).ok(),
+            "{}", canonical
+        );
+    }
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "clap")]
+
+use std::time::{Duration, SystemTime};
+
+use clap::error::ErrorKind;
+use clap::{Arg, Command};
+use interim::{Dialect, Interval};
+
+fn command() -> Command {
+    Command::new("backup")
+        .arg(
+            Arg::new("since")
+                .long("since")
+                .value_parser(interim::clap::datetime::<SystemTime>(Dialect::Uk))
+                .long_help(interim::clap::DATETIME_HELP),
+        )
+        .arg(
+            Arg::new("every")
+                .long("every")
+                .value_parser(interim::clap::duration())
+                .long_help(interim::clap::DURATION_HELP),
+        )
+}
+
+#[test]
+fn values() {
+    let matches = command()
+        .try_get_matches_from(["backup", "--since", "3 days ago", "--every", "15m"])
+        .unwrap();
+    assert_eq!(
+        matches.get_one::<Interval>("every"),
+        Some(&Interval::Seconds(15 * 60))
+    );
+
+    let since = *matches.get_one::<SystemTime>("since").unwrap();
+    let ago = SystemTime::now().duration_since(since).unwrap();
+    let three_days = Duration::from_secs(3 * 86400);
+    assert!(ago >= three_days && ago < three_days + Duration::from_secs(60));
+}
+
+#[test]
+fn same_start_time() {
+    let matches = command()
+        .arg(
+            Arg::new("until")
+                .long("until")
+                .value_parser(interim::clap::datetime::<SystemTime>(Dialect::Uk)),
+        )
+        .try_get_matches_from(["backup", "--since", "1 hour ago", "--until", "1 hour"])
+        .unwrap();
+    let since = matches.get_one::<SystemTime>("since").unwrap();
+    let until = matches.get_one::<SystemTime>("until").unwrap();
+    assert_eq!(
+        until.duration_since(*since).unwrap(),
+        Duration::from_secs(7200)
+    );
+}
+
+#[test]
+fn errors() {
+    let err = command()
+        .try_get_matches_from(["backup", "--since", "next blursday"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    let message = err.to_string();
+    assert!(
+        message.contains("invalid value 'next blursday' for '--since <since>'"),
+        "{message}"
+    );
+    assert!(
+        message.contains("\n  next blursday\n       ^^^^^^^^\n"),
+        "{message}"
+    );
+
+    let err = command()
+        .try_get_matches_from(["backup", "--every", "friday"])
+        .unwrap_err();
+    let message = err.to_string();
+    assert!(
+        message.contains("expected relative date, found a named date"),
+        "{message}"
+    );
+    assert!(!message.contains('^'), "{message}");
+
+    let err = command()
+        .try_get_matches_from(["backup", "--since", "2018-04-01 10:"])
+        .unwrap_err();
+    let message = err.to_string();
+    assert!(
+        message.contains("\n  2018-04-01 10:\n                ^\n"),
+        "{message}"
+    );
+}
+
+#[test]
+fn help() {
+    let help = command().render_long_help().to_string();
+    assert!(help.contains("next friday 8pm"), "{help}");
+    assert!(help.contains("3 days ago"), "{help}");
+}
+
+#[cfg(feature = "jiff_0_2")]
+#[test]
+fn zoned() {
+    let matches = Command::new("remind")
+        .arg(
+            Arg::new("at")
+                .long("at")
+                .value_parser(interim::clap::datetime::<jiff_0_2::Zoned>(Dialect::Us)),
+        )
+        .try_get_matches_from(["remind", "--at", "tomorrow 9am"])
+        .unwrap();
+    let at = matches.get_one::<jiff_0_2::Zoned>("at").unwrap();
+    assert_eq!((at.hour(), at.minute()), (9, 0));
+    assert_eq!(at.time_zone(), &jiff_0_2::tz::TimeZone::system());
+}
@@ -0,0 +1,24 @@
+// not every test uses every helper
+#![allow(dead_code)]
+
+use std::ops::Range;
+
+use interim::civil::{Date, DateTime, Time};
+use proptest::prelude::*;
+
+/// The date and time at +01:00
+pub fn at(year: i32, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> DateTime {
+    let date = Date::new(year, month, day).unwrap();
+    DateTime::from_parts(date, Time::new(hour, minute, second).unwrap(), 3600).unwrap()
+}
+
+/// Date-times at +01:00 in the years
+pub fn date_time(years: Range<i32>) -> impl Strategy<Value = DateTime> {
+    (years, 1..=12_u8, 1..=31_u8, 0..24_u8, 0..60_u8, 0..60_u8).prop_filter_map(
+        "invalid date",
+        |(year, month, day, hour, minute, second)| {
+            let date = Date::new(year, month, day)?;
+            DateTime::from_parts(date, Time::new(hour, minute, second)?, 3600)
+        },
+    )
+}
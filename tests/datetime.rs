@@ -1,38 +1,286 @@
+// every backend is checked with the conformance harness, which needs std
+#![cfg(feature = "std")]
+
 use std::fmt::Debug;
 
-use interim::{datetime::DateTime, parse_date_string, Dialect};
+use interim::{
+    conformance::Harness, datetime::DateTime, parse_date_string, parse_date_string_with, DateError,
+    Dialect, Disambiguation, Options,
+};
 
-#[allow(unused)]
-trait FormatDateTime: DateTime + Debug {
-    /// Datetime representing 2018-03-21T11:00:00+02:00
-    fn base() -> Self;
-    /// RFC3339 format, eg 2018-03-21T11:00:00+02:00
-    fn format(&self) -> String;
+mod civil {
+    use super::*;
+
+    use interim::civil::{Date, DateTime, Time};
+
+    fn base_zoned() -> DateTime {
+        let date = Date::new(2018, 3, 21).unwrap();
+        DateTime::from_parts(date, Time::new(11, 00, 00).unwrap(), 7200).unwrap()
+    }
+
+    fn format_zoned(date: &DateTime) -> String {
+        date.to_string()
+    }
+
+    fn zoned() -> Harness<DateTime> {
+        Harness::zoned(base_zoned(), format_zoned)
+    }
+
+    #[test]
+    fn acceptance() {
+        zoned().acceptance();
+    }
+
+    #[test]
+    #[cfg(any(feature = "jiff_0_2", feature = "chrono_tz_0_10"))]
+    fn named_zones() {
+        zoned().named_zones();
+        zoned().disambiguation();
+        zoned().named_zone_conversion();
+    }
+
+    #[test]
+    fn conversion() {
+        zoned().conversion();
+    }
+
+    #[test]
+    fn keep_offset() {
+        zoned().keep_offset();
+    }
+
+    #[test]
+    fn expression() {
+        use interim::Expression;
+
+        fn send_sync_clone<T: Send + Sync + Clone>(_: &T) {}
+
+        for input in [
+            "next monday 9am",
+            "1 month",
+            "@1700000000",
+            "9am PST in CET",
+            "8/11",
+        ] {
+            let expression = Expression::parse(input, Dialect::Us).unwrap();
+            send_sync_clone(&expression);
+            for now in [base_zoned(), DateTime::from_unix(0, 0, -3600).unwrap()] {
+                assert_eq!(
+                    expression.evaluate(now),
+                    parse_date_string(input, now, Dialect::Us),
+                    "{input:?} at {now}"
+                );
+            }
+        }
+
+        let bare = Options::new(Dialect::Uk).bare_timestamps(true);
+        let expression = Expression::parse("1700000000", bare).unwrap();
+        assert_eq!(
+            expression.evaluate(base_zoned()).unwrap().unix_timestamp(),
+            1_700_000_000
+        );
+        assert!(Expression::parse("9am bananas", Dialect::Uk).is_err());
+    }
+
+    #[test]
+    fn date_and_time_of_day() {
+        use interim::{parse_date, parse_time_of_day};
+
+        let now = base_zoned();
+        let date = |input| parse_date(input, now, Dialect::Uk);
+        let time = |input| parse_time_of_day(input, now, Dialect::Uk);
+
+        assert_eq!(date("next friday"), Ok(Date::new(2018, 3, 30).unwrap()));
+        assert_eq!(date("2017-06-30"), Ok(Date::new(2017, 6, 30).unwrap()));
+        assert_eq!(date("36 hours"), Ok(Date::new(2018, 3, 22).unwrap()));
+        assert_eq!(date("@0"), Ok(Date::new(1970, 1, 1).unwrap()));
+        assert_eq!(date("friday 8pm"), Err(DateError::ExpectedDateOnly));
+        assert_eq!(date("9am"), Err(DateError::ExpectedDateOnly));
+
+        assert_eq!(time("3:30pm"), Ok(Time::new(15, 30, 0).unwrap()));
+        assert_eq!(time("noon"), Ok(Time::new(12, 0, 0).unwrap()));
+        assert_eq!(time("9am UTC"), Ok(Time::new(11, 0, 0).unwrap()));
+        assert_eq!(time("tomorrow 9am"), Err(DateError::ExpectedTimeOnly));
+        assert_eq!(time("friday"), Err(DateError::ExpectedTimeOnly));
+    }
+
+    #[test]
+    fn calendar() {
+        let date = |y, m, d| Date::new(y, m, d).unwrap();
+        let midnight = Time::new(0, 0, 0).unwrap();
+
+        assert_eq!(Date::new(2024, 2, 29), Some(date(2024, 2, 29)));
+        assert_eq!(Date::new(2023, 2, 29), None);
+        assert_eq!(Date::new(1900, 2, 29), None);
+        assert_eq!(Date::new(2000, 2, 29), Some(date(2000, 2, 29)));
+        assert_eq!(Date::new(2018, 13, 1), None);
+        assert_eq!(Time::new(24, 0, 0), None);
+        assert_eq!(date(2018, 3, 21).weekday(), 2);
+        assert_eq!(date(1969, 12, 31).weekday(), 2);
+
+        let epoch = DateTime::from_parts(date(1970, 1, 1), midnight, 0).unwrap();
+        assert_eq!(epoch.unix_timestamp(), 0);
+        assert_eq!(
+            DateTime::from_unix(-1, 0, 0).unwrap().to_string(),
+            "1969-12-31T23:59:59+00:00"
+        );
+        assert_eq!(
+            DateTime::from_unix(-62_135_596_800, 0, -3600)
+                .unwrap()
+                .to_string(),
+            "0000-12-31T23:00:00-01:00"
+        );
+        assert_eq!(
+            DateTime::from_unix(1_700_000_000, 123_000, 19800)
+                .unwrap()
+                .to_string(),
+            "2023-11-15T03:43:20.123000+05:30"
+        );
+        assert_eq!(
+            DateTime::from_parts(date(2018, 3, 21), midnight, 86400),
+            None
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        super::out_of_range(base_zoned(), &TOO_LONG);
+    }
+}
+
+#[cfg(feature = "std")]
+mod system_time {
+    use super::*;
+
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use interim::civil;
+
+    fn base_utc() -> SystemTime {
+        // 2018-03-21T11:00:00Z
+        UNIX_EPOCH + Duration::from_secs(1_521_630_000)
+    }
+
+    fn format_utc(date: &SystemTime) -> String {
+        let (secs, micros) = match date.duration_since(UNIX_EPOCH) {
+            Ok(after) => (after.as_secs() as i64, after.subsec_micros()),
+            Err(before) => {
+                let before = before.duration();
+                let micros = before.subsec_micros();
+                let secs = -(before.as_secs() as i64) - i64::from(micros > 0);
+                (secs, (1_000_000 - micros) % 1_000_000)
+            }
+        };
+        civil::DateTime::from_unix(secs, micros, 0)
+            .unwrap()
+            .to_string()
+    }
+
+    fn utc() -> Harness<SystemTime> {
+        Harness::utc(base_utc(), format_utc)
+    }
+
+    #[test]
+    fn acceptance() {
+        utc().acceptance();
+    }
+
+    #[test]
+    #[cfg(any(feature = "jiff_0_2", feature = "chrono_tz_0_10"))]
+    fn named_zones() {
+        utc().named_zones();
+    }
+
+    #[test]
+    fn before_epoch() {
+        let date = parse_date_string("1969-12-31 23:59:59", base_utc(), Dialect::Uk);
+        assert_eq!(date, Ok(UNIX_EPOCH - Duration::from_secs(1)));
+        let date = parse_date_string("@-1.5", base_utc(), Dialect::Uk);
+        assert_eq!(date, Ok(UNIX_EPOCH - Duration::from_millis(1500)));
+        let date = parse_date_string(
+            "tomorrow",
+            UNIX_EPOCH - Duration::from_millis(1500),
+            Dialect::Uk,
+        );
+        assert_eq!(
+            date,
+            Ok(UNIX_EPOCH + Duration::from_secs(86400 - 2) + Duration::from_millis(500))
+        );
+    }
+    #[test]
+    fn beyond_calendar() {
+        // valid, but too far from the epoch for a calendar date
+        let far = UNIX_EPOCH + Duration::from_secs(1 << 62);
+        for input in ["tomorrow", "9am", "@0", "2018-03-21"] {
+            let err = parse_date_string(input, far, Dialect::Uk);
+            assert_eq!(err, Err(DateError::MissingDate), "{input:?}");
+        }
+        let err = interim::difference(&base_utc(), &far);
+        assert_eq!(err.err(), Some(DateError::MissingDate));
+    }
+
+    #[test]
+    fn out_of_range() {
+        super::out_of_range(base_utc(), &TOO_LONG);
+    }
 }
 
 #[cfg(feature = "chrono_0_4")]
 mod chrono_0_4 {
     use super::*;
 
-    use chrono::{DateTime, FixedOffset, TimeZone};
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
 
-    impl FormatDateTime for DateTime<FixedOffset> {
-        fn base() -> Self {
-            FixedOffset::east_opt(7200)
-                .unwrap()
-                .with_ymd_and_hms(2018, 3, 21, 11, 00, 00)
-                .unwrap()
-        }
-        fn format(&self) -> String {
-            self.format("%+").to_string()
-        }
+    fn base_zoned() -> DateTime<FixedOffset> {
+        FixedOffset::east_opt(7200)
+            .unwrap()
+            .with_ymd_and_hms(2018, 3, 21, 11, 00, 00)
+            .unwrap()
+    }
+
+    fn format_zoned(date: &DateTime<FixedOffset>) -> String {
+        date.format("%+").to_string()
+    }
+
+    fn zoned() -> Harness<DateTime<FixedOffset>> {
+        Harness::zoned(base_zoned(), format_zoned)
+    }
+
+    fn base_naive() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2018, 3, 21)
+            .unwrap()
+            .and_hms_opt(11, 00, 00)
+            .unwrap()
+    }
+
+    fn format_naive(date: &NaiveDateTime) -> String {
+        date.format("%FT%T%.f").to_string()
+    }
+
+    fn naive() -> Harness<NaiveDateTime> {
+        Harness::naive(base_naive(), format_naive)
     }
 
     #[test]
     fn acceptance() {
-        super::acceptance::<DateTime<FixedOffset>>();
+        zoned().acceptance();
+    }
+
+    #[test]
+    fn acceptance_naive() {
+        naive().acceptance();
+    }
+
+    #[test]
+    #[cfg(any(feature = "jiff_0_2", feature = "chrono_tz_0_10"))]
+    fn named_zones() {
+        zoned().named_zones();
+        zoned().disambiguation();
+        zoned().named_zone_conversion();
+        naive().named_zones();
     }
 
+    #[cfg(feature = "chrono_tz_0_10")]
     #[test]
     /// <https://github.com/conradludgate/interim/issues/12>
     fn regression_12() {
@@ -48,34 +296,427 @@ mod chrono_0_4 {
         assert_eq!(without_timezone, with_timezone);
         assert_eq!(with_timezone.to_string(), "2024-06-01 12:00:00 PDT");
     }
+
+    #[cfg(feature = "chrono_tz_0_10")]
+    #[test]
+    fn dst_transitions() {
+        let now = chrono_tz::America::Los_Angeles
+            .with_ymd_and_hms(2024, 1, 1, 12, 00, 00)
+            .unwrap();
+        super::dst_transitions(now, |date| date.format("%FT%T%:z").to_string());
+    }
+
+    #[cfg(feature = "chrono_tz_0_10")]
+    fn assert_local<Tz: interim::datetime::ChronoTimeZone>(
+        now: DateTime<Tz>,
+        input: &str,
+        disambiguation: Disambiguation,
+        expected: &str,
+    ) {
+        let options = Options::new(Dialect::Uk).disambiguation(disambiguation);
+        let date = parse_date_string_with(input, now, options).unwrap();
+        assert_eq!(
+            date.fixed_offset().format("%FT%T%:z").to_string(),
+            expected,
+            "{input:?} with {disambiguation:?}"
+        );
+    }
+
+    #[cfg(feature = "chrono_tz_0_10")]
+    #[test]
+    /// Local times used to be resolved with the offset in effect at the same time in UTC,
+    /// which is wrong in the hours either side of a DST transition
+    fn local_time_southern_hemisphere() {
+        use Disambiguation::*;
+
+        let sydney = chrono_tz::Australia::Sydney
+            .with_ymd_and_hms(2024, 1, 1, 12, 00, 00)
+            .unwrap();
+        assert_local(
+            sydney,
+            "2024-10-06 01:00",
+            Reject,
+            "2024-10-06T01:00:00+10:00",
+        );
+        assert_local(
+            sydney,
+            "2024-04-06 20:00",
+            Reject,
+            "2024-04-06T20:00:00+11:00",
+        );
+        assert_local(
+            sydney,
+            "2024-10-06 02:30",
+            Compatible,
+            "2024-10-06T03:30:00+11:00",
+        );
+        assert_local(
+            sydney,
+            "2024-10-06 02:30",
+            Earlier,
+            "2024-10-06T01:30:00+10:00",
+        );
+        assert_local(
+            sydney,
+            "2024-04-07 02:30",
+            Earlier,
+            "2024-04-07T02:30:00+11:00",
+        );
+        assert_local(
+            sydney,
+            "2024-04-07 02:30",
+            Later,
+            "2024-04-07T02:30:00+10:00",
+        );
+
+        // clocks in Santiago skip midnight
+        let santiago = chrono_tz::America::Santiago
+            .with_ymd_and_hms(2024, 1, 1, 12, 00, 00)
+            .unwrap();
+        assert_local(
+            santiago,
+            "2024-09-08",
+            Compatible,
+            "2024-09-08T01:00:00-03:00",
+        );
+        assert_local(santiago, "2024-09-08", Earlier, "2024-09-07T23:00:00-04:00");
+        assert_local(
+            santiago,
+            "2024-04-06 23:30",
+            Later,
+            "2024-04-06T23:30:00-04:00",
+        );
+    }
+
+    #[cfg(feature = "chrono_tz_0_10")]
+    #[test]
+    fn local_time_northern_hemisphere() {
+        use Disambiguation::*;
+
+        let los_angeles = chrono_tz::America::Los_Angeles
+            .with_ymd_and_hms(2024, 1, 1, 12, 00, 00)
+            .unwrap();
+        assert_local(
+            los_angeles,
+            "2024-11-03 08:00",
+            Reject,
+            "2024-11-03T08:00:00-08:00",
+        );
+        assert_local(
+            los_angeles,
+            "2024-03-10 00:30",
+            Reject,
+            "2024-03-10T00:30:00-08:00",
+        );
+
+        let berlin = chrono_tz::Europe::Berlin
+            .with_ymd_and_hms(2024, 1, 1, 12, 00, 00)
+            .unwrap();
+        assert_local(
+            berlin,
+            "2024-03-31 01:30",
+            Reject,
+            "2024-03-31T01:30:00+01:00",
+        );
+        assert_local(
+            berlin,
+            "2024-03-31 02:30",
+            Compatible,
+            "2024-03-31T03:30:00+02:00",
+        );
+        assert_local(
+            berlin,
+            "2024-10-27 02:30",
+            Compatible,
+            "2024-10-27T02:30:00+02:00",
+        );
+        assert_local(
+            berlin,
+            "2024-10-27 02:30",
+            Later,
+            "2024-10-27T02:30:00+01:00",
+        );
+        // relative dates keep the wall-clock time across the transition
+        assert_local(
+            berlin,
+            "2024-03-30 12:00",
+            Reject,
+            "2024-03-30T12:00:00+01:00",
+        );
+        let saturday = chrono_tz::Europe::Berlin
+            .with_ymd_and_hms(2024, 3, 30, 12, 00, 00)
+            .unwrap();
+        assert_local(saturday, "tomorrow", Reject, "2024-03-31T12:00:00+02:00");
+    }
+
+    #[cfg(feature = "chrono_tz_0_10")]
+    #[test]
+    fn explicit_offset() {
+        use Disambiguation::*;
+
+        // the offset of the base timezone changes on this day
+        let los_angeles = chrono_tz::America::Los_Angeles
+            .with_ymd_and_hms(2024, 1, 1, 12, 00, 00)
+            .unwrap();
+        assert_local(
+            los_angeles,
+            "2024-03-10 12:00 +00:00",
+            Reject,
+            "2024-03-10T05:00:00-07:00",
+        );
+        assert_local(
+            los_angeles,
+            "2024-03-10 02:30 -08:00",
+            Reject,
+            "2024-03-10T03:30:00-07:00",
+        );
+        assert_local(
+            los_angeles,
+            "2024-11-03 23:30 +05:30",
+            Reject,
+            "2024-11-03T10:00:00-08:00",
+        );
+
+        // chrono_tz can't represent a bare offset
+        let options = Options::new(Dialect::Uk).keep_offset(true);
+        let err = parse_date_string_with("9am +05:30", los_angeles, options);
+        assert_eq!(err, Err(DateError::UnsupportedTimeZone));
+        let date = parse_date_string_with("9am Asia/Kolkata", los_angeles, options);
+        assert_eq!(date.unwrap().to_string(), "2024-01-01 09:00:00 IST");
+    }
+
+    #[test]
+    fn conversion() {
+        zoned().conversion();
+    }
+
+    #[test]
+    fn keep_offset() {
+        zoned().keep_offset();
+    }
+
+    #[test]
+    fn conversion_other_timezones() {
+        let utc = base_zoned().with_timezone(&Utc);
+        let date = parse_date_string("now in UTC", utc, Dialect::Uk).unwrap();
+        assert_eq!(date, utc);
+        let date = parse_date_string("9am to +05:30", utc, Dialect::Uk).unwrap();
+        assert_eq!(date.to_string(), "2018-03-21 09:00:00 UTC");
+
+        let local = utc.with_timezone(&chrono::Local);
+        let err = parse_date_string("now in UTC", local, Dialect::Uk);
+        assert_eq!(err, Err(DateError::UnsupportedTimeZone));
+    }
+
+    #[test]
+    #[cfg(feature = "chrono_tz_0_10")]
+    fn conversion_chrono_tz() {
+        let london = base_zoned().with_timezone(&chrono_tz::Europe::London);
+        let date = parse_date_string("tomorrow 9am to Asia/Tokyo", london, Dialect::Uk).unwrap();
+        assert_eq!(date.to_string(), "2018-03-22 18:00:00 JST");
+        let date = parse_date_string("now in UTC", london, Dialect::Uk).unwrap();
+        assert_eq!(date.to_string(), "2018-03-21 09:00:00 UTC");
+        let err = parse_date_string("now in +05:30", london, Dialect::Uk);
+        assert_eq!(err, Err(DateError::UnsupportedTimeZone));
+    }
+
+    #[test]
+    fn out_of_range() {
+        let inputs = [TOO_LONG.as_slice(), &TOO_LONG_FOR_LIBRARIES].concat();
+        super::out_of_range(base_zoned(), &inputs);
+        super::out_of_range(base_naive(), &inputs);
+    }
 }
 
 #[cfg(feature = "time_0_3")]
 mod time_0_3 {
     use super::*;
 
-    use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+    use interim::datetime::ZonedDateTime;
+    use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset};
 
-    impl FormatDateTime for OffsetDateTime {
-        fn base() -> Self {
-            PrimitiveDateTime::new(
-                Date::from_calendar_date(2018, Month::March, 21).unwrap(),
-                Time::from_hms(11, 00, 00).unwrap(),
-            )
-            .assume_offset(UtcOffset::from_whole_seconds(7200).unwrap())
-        }
-        fn format(&self) -> String {
-            let format = time::format_description::parse(
-                "[year]-[month]-[day]T[hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]",
-            ).unwrap();
+    fn base_zoned() -> OffsetDateTime {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(2018, Month::March, 21).unwrap(),
+            Time::from_hms(11, 00, 00).unwrap(),
+        )
+        .assume_offset(UtcOffset::from_whole_seconds(7200).unwrap())
+    }
+
+    #[allow(deprecated)]
+    fn format_zoned(date: &OffsetDateTime) -> String {
+        let format =
+            time::format_description::parse("[offset_hour sign:mandatory]:[offset_minute]")
+                .unwrap();
+
+        let local = format_naive(&PrimitiveDateTime::new(date.date(), date.time()));
+        local + &date.offset().format(&format).unwrap()
+    }
+
+    fn zoned() -> Harness<OffsetDateTime> {
+        Harness::zoned(base_zoned(), format_zoned)
+    }
+
+    fn base_utc() -> UtcDateTime {
+        UtcDateTime::new(
+            Date::from_calendar_date(2018, Month::March, 21).unwrap(),
+            Time::from_hms(11, 00, 00).unwrap(),
+        )
+    }
+
+    fn format_utc(date: &UtcDateTime) -> String {
+        format_naive(&PrimitiveDateTime::new(date.date(), date.time())) + "+00:00"
+    }
+
+    fn utc() -> Harness<UtcDateTime> {
+        Harness::utc(base_utc(), format_utc)
+    }
+
+    fn base_naive() -> PrimitiveDateTime {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(2018, Month::March, 21).unwrap(),
+            Time::from_hms(11, 00, 00).unwrap(),
+        )
+    }
+
+    // with fractional seconds only when there are any
+    #[allow(deprecated)]
+    fn format_naive(date: &PrimitiveDateTime) -> String {
+        let format =
+            time::format_description::parse("[year]-[month]-[day]T[hour]:[minute]:[second]")
+                .unwrap();
 
-            OffsetDateTime::format(*self, &format).unwrap()
+        let formatted = PrimitiveDateTime::format(*date, &format).unwrap();
+        match date.microsecond() {
+            0 => formatted,
+            micros => format!("{formatted}.{micros:06}"),
         }
     }
 
+    fn naive() -> Harness<PrimitiveDateTime> {
+        Harness::naive(base_naive(), format_naive)
+    }
+
     #[test]
     fn acceptance() {
-        super::acceptance::<OffsetDateTime>();
+        zoned().acceptance();
+    }
+
+    #[test]
+    fn acceptance_naive() {
+        naive().acceptance();
+    }
+
+    #[test]
+    fn acceptance_utc() {
+        utc().acceptance();
+    }
+
+    #[test]
+    #[cfg(any(feature = "jiff_0_2", feature = "chrono_tz_0_10"))]
+    fn named_zones() {
+        zoned().named_zones();
+        zoned().disambiguation();
+        zoned().named_zone_conversion();
+        naive().named_zones();
+        utc().named_zones();
+    }
+
+    #[test]
+    fn conversion() {
+        zoned().conversion();
+    }
+
+    #[test]
+    fn keep_offset() {
+        zoned().keep_offset();
+    }
+
+    /// Pacific time in 2024, from the 10th of March to the 3rd of November
+    fn los_angeles(utc: UtcDateTime) -> UtcOffset {
+        let pdt = time::macros::utc_datetime!(2024-03-10 10:00)
+            ..time::macros::utc_datetime!(2024-11-03 09:00);
+        let hours = if pdt.contains(&utc) { -7 } else { -8 };
+        UtcOffset::from_hms(hours, 0, 0).unwrap()
+    }
+
+    fn plus_two(_: UtcDateTime) -> UtcOffset {
+        UtcOffset::from_hms(2, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn acceptance_zoned() {
+        let harness = Harness::zoned(ZonedDateTime::new(base_zoned(), plus_two), |date| {
+            format_zoned(&date.date_time())
+        });
+        harness.acceptance();
+        harness.conversion();
+        harness.keep_offset();
+        #[cfg(any(feature = "jiff_0_2", feature = "chrono_tz_0_10"))]
+        {
+            harness.named_zones();
+            harness.disambiguation();
+            harness.named_zone_conversion();
+        }
+    }
+
+    #[test]
+    fn zone_captured() {
+        // a zone known only at runtime
+        let offset = UtcOffset::from_hms(5, 30, 0).unwrap();
+        let now = ZonedDateTime::new(base_zoned(), move |_| offset);
+        let date = parse_date_string("tomorrow 9am", now, Dialect::Uk).unwrap();
+        assert_eq!(format_zoned(&date.date_time()), "2018-03-22T09:00:00+05:30");
+    }
+
+    #[test]
+    fn dst_transitions() {
+        let now = ZonedDateTime::new(
+            time::macros::datetime!(2024-01-01 12:00 -08:00),
+            los_angeles,
+        );
+        super::dst_transitions(now, |date| format_zoned(&date.date_time()));
+    }
+
+    #[test]
+    fn local_time_across_transition() {
+        let assert = |now: OffsetDateTime, input: &str, expected: &str| {
+            let now = ZonedDateTime::new(now, los_angeles);
+            let date = parse_date_string(input, now, Dialect::Us).unwrap();
+            assert_eq!(format_zoned(&date.date_time()), expected, "{input:?}");
+        };
+
+        let before = time::macros::datetime!(2024-03-09 12:00 -08:00);
+        assert(before, "tomorrow 9am", "2024-03-10T09:00:00-07:00");
+        assert(before, "next week", "2024-03-16T12:00:00-07:00");
+        // elapsed time is exact
+        assert(before, "24 hours", "2024-03-10T13:00:00-07:00");
+        assert(before, "1 day", "2024-03-10T12:00:00-07:00");
+
+        let after = time::macros::datetime!(2024-11-04 12:00 -08:00);
+        assert(after, "yesterday 9am", "2024-11-03T09:00:00-08:00");
+        assert(after, "@1730622600", "2024-11-03T01:30:00-07:00");
+        assert(after, "@1730626200", "2024-11-03T01:30:00-08:00");
+
+        // a conversion keeps the offset it gives
+        let date = parse_date_string(
+            "now in UTC",
+            ZonedDateTime::new(before, los_angeles),
+            Dialect::Us,
+        );
+        assert_eq!(
+            format_zoned(&date.unwrap().date_time()),
+            "2024-03-09T20:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        let inputs = [TOO_LONG.as_slice(), &TOO_LONG_FOR_LIBRARIES].concat();
+        super::out_of_range(base_zoned(), &inputs);
+        super::out_of_range(base_utc(), &inputs);
+        super::out_of_range(base_naive(), &inputs);
+        super::out_of_range(ZonedDateTime::new(base_zoned(), plus_two), &inputs);
     }
 }
 
@@ -83,23 +724,94 @@ mod time_0_3 {
 mod jiff_0_1 {
     use super::*;
 
-    use ::jiff_0_1::{civil::Date, civil::DateTime, civil::Time, tz::Offset, tz::TimeZone, Zoned};
+    use ::jiff_0_1::{
+        civil::Date, civil::DateTime, civil::Time, tz::Offset, tz::TimeZone, Timestamp, Zoned,
+    };
 
-    impl FormatDateTime for Zoned {
-        fn base() -> Self {
-            let tz = TimeZone::fixed(Offset::from_seconds(7200).unwrap());
-            let base =
-                DateTime::from_parts(Date::constant(2018, 3, 21), Time::constant(11, 00, 00, 0));
-            tz.to_zoned(base).unwrap()
-        }
-        fn format(&self) -> String {
-            self.strftime("%FT%T%:z").to_string()
-        }
+    fn base_zoned() -> Zoned {
+        let tz = TimeZone::fixed(Offset::from_seconds(7200).unwrap());
+        let base = DateTime::from_parts(Date::constant(2018, 3, 21), Time::constant(11, 00, 00, 0));
+        tz.to_zoned(base).unwrap()
+    }
+
+    fn format_zoned(date: &Zoned) -> String {
+        date.strftime("%FT%T%.f%:z").to_string()
+    }
+
+    fn zoned() -> Harness<Zoned> {
+        Harness::zoned(base_zoned(), format_zoned)
+    }
+
+    fn base_utc() -> Timestamp {
+        let base = DateTime::from_parts(Date::constant(2018, 3, 21), Time::constant(11, 00, 00, 0));
+        Offset::UTC.to_timestamp(base).unwrap()
+    }
+
+    fn format_utc(date: &Timestamp) -> String {
+        Offset::UTC
+            .to_datetime(*date)
+            .strftime("%FT%T%.f+00:00")
+            .to_string()
+    }
+
+    fn utc() -> Harness<Timestamp> {
+        Harness::utc(base_utc(), format_utc)
+    }
+
+    fn base_naive() -> DateTime {
+        DateTime::from_parts(Date::constant(2018, 3, 21), Time::constant(11, 00, 00, 0))
+    }
+
+    fn format_naive(date: &DateTime) -> String {
+        date.strftime("%FT%T%.f").to_string()
+    }
+
+    fn naive() -> Harness<DateTime> {
+        Harness::naive(base_naive(), format_naive)
     }
 
     #[test]
     fn acceptance() {
-        super::acceptance::<Zoned>();
+        zoned().acceptance();
+    }
+
+    #[test]
+    fn acceptance_naive() {
+        naive().acceptance();
+    }
+
+    #[test]
+    fn acceptance_utc() {
+        utc().acceptance();
+    }
+
+    #[test]
+    #[cfg(any(feature = "jiff_0_2", feature = "chrono_tz_0_10"))]
+    fn named_zones() {
+        zoned().named_zones();
+        zoned().disambiguation();
+        zoned().named_zone_conversion();
+        naive().named_zones();
+        utc().named_zones();
+    }
+
+    #[test]
+    fn conversion() {
+        zoned().conversion();
+    }
+
+    #[test]
+    fn keep_offset() {
+        zoned().keep_offset();
+    }
+
+    #[test]
+    fn dst_transitions() {
+        let tz = TimeZone::get("America/Los_Angeles").unwrap();
+        let base = DateTime::from_parts(Date::constant(2024, 1, 1), Time::constant(12, 00, 00, 0));
+        super::dst_transitions(tz.to_zoned(base).unwrap(), |date| {
+            date.strftime("%FT%T%:z").to_string()
+        });
     }
 
     #[test]
@@ -119,29 +831,107 @@ mod jiff_0_1 {
             "2024-06-01T12:00:00-07:00[America/Los_Angeles]"
         );
     }
+
+    #[test]
+    fn out_of_range() {
+        let inputs = [TOO_LONG.as_slice(), &TOO_LONG_FOR_LIBRARIES].concat();
+        super::out_of_range(base_zoned(), &inputs);
+        super::out_of_range(base_utc(), &inputs);
+        super::out_of_range(base_naive(), &inputs);
+    }
 }
 
 #[cfg(feature = "jiff_0_2")]
 mod jiff_0_2 {
     use super::*;
 
-    use ::jiff_0_2::{civil::Date, civil::DateTime, civil::Time, tz::Offset, tz::TimeZone, Zoned};
+    use ::jiff_0_2::{
+        civil::Date, civil::DateTime, civil::Time, tz::Offset, tz::TimeZone, Timestamp, Zoned,
+    };
 
-    impl FormatDateTime for Zoned {
-        fn base() -> Self {
-            let tz = TimeZone::fixed(Offset::from_seconds(7200).unwrap());
-            let base =
-                DateTime::from_parts(Date::constant(2018, 3, 21), Time::constant(11, 00, 00, 0));
-            tz.to_zoned(base).unwrap()
-        }
-        fn format(&self) -> String {
-            self.strftime("%FT%T%:z").to_string()
-        }
+    fn base_zoned() -> Zoned {
+        let tz = TimeZone::fixed(Offset::from_seconds(7200).unwrap());
+        let base = DateTime::from_parts(Date::constant(2018, 3, 21), Time::constant(11, 00, 00, 0));
+        tz.to_zoned(base).unwrap()
+    }
+
+    fn format_zoned(date: &Zoned) -> String {
+        date.strftime("%FT%T%.f%:z").to_string()
+    }
+
+    fn zoned() -> Harness<Zoned> {
+        Harness::zoned(base_zoned(), format_zoned)
+    }
+
+    fn base_utc() -> Timestamp {
+        let base = DateTime::from_parts(Date::constant(2018, 3, 21), Time::constant(11, 00, 00, 0));
+        Offset::UTC.to_timestamp(base).unwrap()
+    }
+
+    fn format_utc(date: &Timestamp) -> String {
+        Offset::UTC
+            .to_datetime(*date)
+            .strftime("%FT%T%.f+00:00")
+            .to_string()
+    }
+
+    fn utc() -> Harness<Timestamp> {
+        Harness::utc(base_utc(), format_utc)
+    }
+
+    fn base_naive() -> DateTime {
+        DateTime::from_parts(Date::constant(2018, 3, 21), Time::constant(11, 00, 00, 0))
+    }
+
+    fn format_naive(date: &DateTime) -> String {
+        date.strftime("%FT%T%.f").to_string()
+    }
+
+    fn naive() -> Harness<DateTime> {
+        Harness::naive(base_naive(), format_naive)
     }
 
     #[test]
     fn acceptance() {
-        super::acceptance::<Zoned>();
+        zoned().acceptance();
+    }
+
+    #[test]
+    fn acceptance_naive() {
+        naive().acceptance();
+    }
+
+    #[test]
+    fn acceptance_utc() {
+        utc().acceptance();
+    }
+
+    #[test]
+    fn named_zones() {
+        zoned().named_zones();
+        zoned().disambiguation();
+        zoned().named_zone_conversion();
+        naive().named_zones();
+        utc().named_zones();
+    }
+
+    #[test]
+    fn conversion() {
+        zoned().conversion();
+    }
+
+    #[test]
+    fn keep_offset() {
+        zoned().keep_offset();
+    }
+
+    #[test]
+    fn dst_transitions() {
+        let tz = TimeZone::get("America/Los_Angeles").unwrap();
+        let base = DateTime::from_parts(Date::constant(2024, 1, 1), Time::constant(12, 00, 00, 0));
+        super::dst_transitions(tz.to_zoned(base).unwrap(), |date| {
+            date.strftime("%FT%T%:z").to_string()
+        });
     }
 
     #[test]
@@ -161,92 +951,96 @@ mod jiff_0_2 {
             "2024-06-01T12:00:00-07:00[America/Los_Angeles]"
         );
     }
+
+    #[test]
+    fn out_of_range() {
+        let inputs = [TOO_LONG.as_slice(), &TOO_LONG_FOR_LIBRARIES].concat();
+        super::out_of_range(base_zoned(), &inputs);
+        super::out_of_range(base_utc(), &inputs);
+        super::out_of_range(base_naive(), &inputs);
+    }
 }
 
+/// `now` must be in America/Los_Angeles
 #[allow(unused)]
-fn acceptance<Dt: FormatDateTime>() {
-    use Dialect::{Uk, Us};
-    fn assert<Dt: FormatDateTime>(input: &str, dialect: Dialect, expected: &str) {
-        let date = match parse_date_string(input, Dt::base(), dialect) {
+fn dst_transitions<Dt: DateTime + Clone + Debug>(now: Dt, format: fn(&Dt) -> String) {
+    let assert = |input: &str, disambiguation: Disambiguation, expected: &str| {
+        let options = Options::new(Dialect::Us).disambiguation(disambiguation);
+        let date = match parse_date_string_with(input, now.clone(), options) {
             Ok(date) => date,
             Err(e) => {
                 panic!("unexpected error attempting to parse {input:?}\n\t{e:?}")
             }
         };
+        assert_eq!(format(&date), expected, "{input:?} with {disambiguation:?}");
+    };
+    let error = |input: &str, expected: DateError| {
+        let options = Options::new(Dialect::Us).disambiguation(Disambiguation::Reject);
+        let err = parse_date_string_with(input, now.clone(), options).unwrap_err();
+        assert_eq!(err, expected, "{input:?}");
+    };
+
+    // clocks went forward from 02:00 to 03:00
+    let gap = "2024-03-10 02:30";
+    assert(gap, Disambiguation::Compatible, "2024-03-10T03:30:00-07:00");
+    assert(gap, Disambiguation::Earlier, "2024-03-10T01:30:00-08:00");
+    assert(gap, Disambiguation::Later, "2024-03-10T03:30:00-07:00");
+    error(gap, DateError::NonexistentTime);
+
+    // clocks went back from 02:00 to 01:00
+    let fold = "2024-11-03 01:30";
+    assert(
+        fold,
+        Disambiguation::Compatible,
+        "2024-11-03T01:30:00-07:00",
+    );
+    assert(fold, Disambiguation::Earlier, "2024-11-03T01:30:00-07:00");
+    assert(fold, Disambiguation::Later, "2024-11-03T01:30:00-08:00");
+    error(fold, DateError::AmbiguousTime);
+
+    // named dates that land in the gap
+    assert(
+        "March 10 2:30am",
+        Disambiguation::Earlier,
+        "2024-03-10T01:30:00-08:00",
+    );
 
-        let actual = date.format();
-        assert_eq!(actual, expected, "unexpected output attempting to format {input:?}.\nexpected: {expected:?}\n  parsed: {date:?} [{actual:?}]");
-    }
-
-    // Day of week - relative to today. May have a time part
-    assert::<Dt>("friday", Uk, "2018-03-23T00:00:00+02:00");
-    assert::<Dt>("friday 10:30", Uk, "2018-03-23T10:30:00+02:00");
-    assert::<Dt>("friday 8pm", Uk, "2018-03-23T20:00:00+02:00");
-    assert::<Dt>("12am", Uk, "2018-03-21T00:00:00+02:00");
-    assert::<Dt>("12pm", Uk, "2018-03-21T12:00:00+02:00");
-    assert::<Dt>("7:26 AM", Uk, "2018-03-21T07:26:00+02:00");
-    assert::<Dt>("7:26 PM", Uk, "2018-03-21T19:26:00+02:00");
-
-    // The day of week is the _next_ day after today, so "Tuesday" is the next Tuesday after Wednesday
-    assert::<Dt>("tues", Uk, "2018-03-27T00:00:00+02:00");
-
-    // The expression 'next Monday' is ambiguous; in the US it means the day following (same as 'Monday')
-    // (This is how the `date` command interprets it)
-    assert::<Dt>("next mon", Us, "2018-03-26T00:00:00+02:00");
-    // but otherwise it means the day in the next week..
-    assert::<Dt>("next mon", Uk, "2018-04-02T00:00:00+02:00");
-
-    assert::<Dt>("last year", Uk, "2017-03-21T00:00:00+02:00");
-    assert::<Dt>("this year", Uk, "2018-03-21T00:00:00+02:00");
-    assert::<Dt>("next year", Uk, "2019-03-21T00:00:00+02:00");
-
-    assert::<Dt>("last fri 9.30", Uk, "2018-03-16T09:30:00+02:00");
-
-    // date expressed as month, day - relative to today. May have a time part
-    assert::<Dt>("8/11", Us, "2018-08-11T00:00:00+02:00");
-    assert::<Dt>("last 8/11", Us, "2017-08-11T00:00:00+02:00");
-    assert::<Dt>("last 8/11 9am", Us, "2017-08-11T09:00:00+02:00");
-    assert::<Dt>("8/11", Uk, "2018-11-08T00:00:00+02:00");
-    assert::<Dt>("last 8/11", Uk, "2017-11-08T00:00:00+02:00");
-    assert::<Dt>("last 8/11 9am", Uk, "2017-11-08T09:00:00+02:00");
-    assert::<Dt>("April 1 8.30pm", Uk, "2018-04-01T20:30:00+02:00");
-
-    // advance by time unit from today
-    // without explicit time, use base time - otherwise override
-    assert::<Dt>("2d", Uk, "2018-03-23T11:00:00+02:00");
-    assert::<Dt>("2d 03:00", Uk, "2018-03-23T03:00:00+02:00");
-    assert::<Dt>("3 weeks", Uk, "2018-04-11T11:00:00+02:00");
-    assert::<Dt>("3h", Uk, "2018-03-21T14:00:00+02:00");
-    assert::<Dt>("6 months", Uk, "2018-09-21T00:00:00+02:00");
-    assert::<Dt>("6 months ago", Uk, "2017-09-21T00:00:00+02:00");
-    assert::<Dt>("3 hours ago", Uk, "2018-03-21T08:00:00+02:00");
-    assert::<Dt>(" -3h", Uk, "2018-03-21T08:00:00+02:00");
-    assert::<Dt>(" -3 month", Uk, "2017-12-21T00:00:00+02:00");
-
-    // absolute date with year, month, day - formal ISO and informal UK or US
-    assert::<Dt>("2017-06-30", Uk, "2017-06-30T00:00:00+02:00");
-    assert::<Dt>("30/06/17", Uk, "2017-06-30T00:00:00+02:00");
-    assert::<Dt>("06/30/17", Us, "2017-06-30T00:00:00+02:00");
-
-    // may be followed by time part, formal and informal
-    assert::<Dt>("2017-06-30 08:20:30", Uk, "2017-06-30T08:20:30+02:00");
-    assert::<Dt>(
-        "2017-06-30 08:20:30 +04:00",
-        Uk,
-        "2017-06-30T06:20:30+02:00",
+    // times either side are unaffected
+    assert(
+        "2024-03-10 01:59",
+        Disambiguation::Reject,
+        "2024-03-10T01:59:00-08:00",
     );
-    assert::<Dt>("2017-06-30 08:20:30 +0400", Uk, "2017-06-30T06:20:30+02:00");
-    assert::<Dt>("2017-06-30T08:20:30Z", Uk, "2017-06-30T10:20:30+02:00");
-    assert::<Dt>("2017-06-30T08:20:30", Uk, "2017-06-30T08:20:30+02:00");
-    assert::<Dt>("2017-06-30 12.20", Uk, "2017-06-30T12:20:00+02:00");
-    assert::<Dt>("2017-06-30 8.20", Uk, "2017-06-30T08:20:00+02:00");
-    assert::<Dt>("2017-06-30 12.15am", Uk, "2017-06-30T00:15:00+02:00");
-    assert::<Dt>("2017-06-30 12.25pm", Uk, "2017-06-30T12:25:00+02:00");
-    assert::<Dt>("2017-06-30 12:30pm", Uk, "2017-06-30T12:30:00+02:00");
-    assert::<Dt>("2017-06-30 8.30pm", Uk, "2017-06-30T20:30:00+02:00");
-    assert::<Dt>("2017-06-30 8:30pm", Uk, "2017-06-30T20:30:00+02:00");
-    assert::<Dt>("2017-06-30 2am", Uk, "2017-06-30T02:00:00+02:00");
-    assert::<Dt>("30 June 2018", Uk, "2018-06-30T00:00:00+02:00");
-    assert::<Dt>("June 30, 2018", Uk, "2018-06-30T00:00:00+02:00");
-    assert::<Dt>("June   30,    2018", Uk, "2018-06-30T00:00:00+02:00");
+    assert(
+        "2024-03-10 03:00",
+        Disambiguation::Reject,
+        "2024-03-10T03:00:00-07:00",
+    );
+}
+
+/// Durations past the end of every calendar
+const TOO_LONG: [&str; 4] = [
+    "9999999999999 days",
+    "999999999999999999 days",
+    "99999999999 years",
+    "in 9999999999999 days",
+];
+
+/// Durations past the end of the calendars of the date-time libraries, which go up to
+/// the year 9999 or about 262000, but not of civil date-times
+#[allow(unused)]
+const TOO_LONG_FOR_LIBRARIES: [&str; 5] = [
+    "99999999 days",
+    "99999999 years",
+    "in 99999999 days",
+    "99999999 days ago",
+    "9999999999999999 seconds",
+];
+
+/// The inputs give an error rather than panicking
+fn out_of_range<Dt: DateTime + Clone + Debug>(now: Dt, inputs: &[&str]) {
+    for input in inputs {
+        let result = parse_date_string(input, now.clone(), Dialect::Uk);
+        assert!(result.is_err(), "{input:?} gave {result:?}");
+    }
 }
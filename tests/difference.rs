@@ -0,0 +1,265 @@
+mod common;
+
+use common::{at, date_time};
+use interim::civil::{Date, DateTime, Time};
+use interim::{difference, difference_with, DifferenceOptions, Interval, Rounding, Unit};
+use proptest::prelude::*;
+
+#[test]
+fn calendar() {
+    let assert = |start, end, expected: &str| {
+        assert_eq!(difference(&start, &end).unwrap().to_string(), expected);
+    };
+
+    let start = at(2024, 1, 31, 9, 0, 0);
+    assert(start, start, "0 seconds");
+    assert(start, at(2024, 1, 31, 9, 0, 1), "1 second");
+    assert(start, at(2024, 2, 29, 9, 0, 0), "1 month");
+    assert(start, at(2024, 2, 29, 8, 0, 0), "28 days 23 hours");
+    assert(start, at(2024, 3, 31, 9, 0, 0), "2 months");
+    assert(
+        start,
+        at(2025, 3, 1, 17, 30, 0),
+        "1 year 1 month 1 day 8 hours 30 minutes",
+    );
+    assert(start, at(2024, 1, 24, 9, 0, 0), "7 days ago");
+    assert(start, at(2023, 12, 31, 9, 0, 0), "1 month ago");
+    assert(
+        start,
+        at(2022, 11, 30, 8, 59, 59),
+        "1 year 2 months 1 second ago",
+    );
+
+    // months are counted from the start, so the difference isn't symmetric
+    assert(
+        at(2024, 3, 31, 9, 0, 0),
+        at(2024, 2, 29, 9, 0, 0),
+        "1 month ago",
+    );
+    assert(
+        at(2024, 2, 29, 9, 0, 0),
+        at(2024, 3, 31, 9, 0, 0),
+        "1 month 2 days",
+    );
+
+    let difference = difference(&start, &at(2025, 3, 1, 17, 30, 0)).unwrap();
+    assert_eq!(
+        (difference.years(), difference.months(), difference.days()),
+        (1, 1, 1)
+    );
+    assert_eq!((difference.hours(), difference.minutes()), (8, 30));
+    assert_eq!(format!("{difference:#}"), "1y1mo1d8h30m");
+}
+
+#[test]
+fn options() {
+    let assert = |options, end, expected: &str| {
+        let start = at(2024, 1, 1, 9, 0, 0);
+        let difference = difference_with(&start, &end, options).unwrap();
+        assert_eq!(difference.to_string(), expected);
+    };
+    let options = DifferenceOptions::new();
+    let end = at(2024, 3, 18, 21, 40, 30);
+
+    assert(
+        options,
+        end,
+        "2 months 17 days 12 hours 40 minutes 30 seconds",
+    );
+    assert(
+        options.largest(Unit::Week),
+        end,
+        "11 weeks 12 hours 40 minutes 30 seconds",
+    );
+    assert(
+        options.largest(Unit::Day),
+        end,
+        "77 days 12 hours 40 minutes 30 seconds",
+    );
+    assert(
+        options.largest(Unit::Hour),
+        end,
+        "1860 hours 40 minutes 30 seconds",
+    );
+    assert(options.largest(Unit::Second), end, "6698430 seconds");
+
+    assert(
+        options.smallest(Unit::Minute),
+        end,
+        "2 months 17 days 12 hours 40 minutes",
+    );
+    assert(options.smallest(Unit::Day), end, "2 months 17 days");
+    assert(options.smallest(Unit::Month), end, "2 months");
+    assert(options.smallest(Unit::Year), end, "0 seconds");
+    let half = options.rounding(Rounding::HalfExpand);
+    assert(
+        half.smallest(Unit::Minute),
+        end,
+        "2 months 17 days 12 hours 41 minutes",
+    );
+    assert(half.smallest(Unit::Day), end, "2 months 18 days");
+    assert(half.smallest(Unit::Month), end, "3 months");
+    assert(half.smallest(Unit::Year), end, "0 seconds");
+    let expand = options.rounding(Rounding::Expand);
+    assert(
+        expand.smallest(Unit::Hour),
+        end,
+        "2 months 17 days 13 hours",
+    );
+    assert(expand.smallest(Unit::Year), end, "1 year");
+
+    // rounding up carries into larger units
+    let end = at(2024, 1, 31, 23, 59, 0);
+    assert(half.smallest(Unit::Hour), end, "30 days 15 hours");
+    assert(
+        half.smallest(Unit::Hour).largest(Unit::Hour),
+        end,
+        "735 hours",
+    );
+    assert(
+        half.smallest(Unit::Week).largest(Unit::Week),
+        end,
+        "4 weeks",
+    );
+
+    // the largest unit is at least the smallest
+    assert(
+        options.largest(Unit::Hour).smallest(Unit::Day),
+        end,
+        "30 days",
+    );
+
+    // halfway is rounded away from zero, in either direction
+    let end = at(2024, 1, 1, 9, 30, 0);
+    assert(half.smallest(Unit::Hour), end, "1 hour");
+    let end = at(2024, 1, 1, 8, 30, 0);
+    assert(half.smallest(Unit::Hour), end, "1 hour ago");
+    assert(options.smallest(Unit::Hour), end, "0 seconds");
+}
+
+#[test]
+fn fractions() {
+    let start = DateTime::from_unix(1_700_000_000, 900_000, 0).unwrap();
+    let end = DateTime::from_unix(1_700_000_002, 100_000, 0).unwrap();
+    assert_eq!(difference(&start, &end).unwrap().to_string(), "1 second");
+    assert_eq!(
+        difference(&end, &start).unwrap().to_string(),
+        "1 second ago"
+    );
+}
+
+#[cfg(feature = "chrono_tz_0_10")]
+#[test]
+fn zoned() {
+    use chrono::TimeZone;
+    use chrono_tz::Europe::London;
+
+    // the clocks go forward an hour at 01:00 on the 31st of March
+    let start = London.with_ymd_and_hms(2024, 3, 30, 12, 0, 0).unwrap();
+    let end = London.with_ymd_and_hms(2024, 3, 31, 12, 0, 0).unwrap();
+    assert_eq!(difference(&start, &end).unwrap().to_string(), "1 day");
+    let options = DifferenceOptions::new().largest(Unit::Hour);
+    assert_eq!(
+        difference_with(&start, &end, options).unwrap().to_string(),
+        "23 hours"
+    );
+    let end = London.with_ymd_and_hms(2024, 3, 31, 11, 30, 0).unwrap();
+    assert_eq!(
+        difference(&start, &end).unwrap().to_string(),
+        "22 hours 30 minutes"
+    );
+}
+
+#[cfg(feature = "chrono_tz_0_10")]
+#[test]
+fn fold() {
+    use chrono::TimeZone;
+    use chrono_tz::Europe::London;
+
+    // the clocks go back an hour at 02:00 on the 27th of October, so 01:00 to 02:00 happens twice
+    let start = London.with_ymd_and_hms(2024, 10, 27, 0, 30, 0).unwrap();
+    let end = London
+        .with_ymd_and_hms(2024, 10, 27, 1, 30, 0)
+        .latest()
+        .unwrap();
+    assert_eq!(difference(&start, &end).unwrap().to_string(), "2 hours");
+    assert_eq!(difference(&end, &start).unwrap().to_string(), "2 hours ago");
+    let end = London
+        .with_ymd_and_hms(2024, 10, 27, 1, 0, 0)
+        .latest()
+        .unwrap();
+    assert_eq!(
+        difference(&start, &end).unwrap().to_string(),
+        "1 hour 30 minutes"
+    );
+    let start = London.with_ymd_and_hms(2024, 10, 26, 0, 30, 0).unwrap();
+    let end = London
+        .with_ymd_and_hms(2024, 10, 27, 1, 30, 0)
+        .latest()
+        .unwrap();
+    assert_eq!(
+        difference(&start, &end).unwrap().to_string(),
+        "1 day 2 hours"
+    );
+}
+
+#[test]
+fn offsets() {
+    let at = |day, hour, minute, offset| {
+        let date = Date::new(2024, 1, day).unwrap();
+        DateTime::from_parts(date, Time::new(hour, minute, 0).unwrap(), offset).unwrap()
+    };
+
+    assert_eq!(
+        difference(&at(1, 12, 0, 7200), &at(1, 12, 0, 0))
+            .unwrap()
+            .to_string(),
+        "2 hours"
+    );
+    assert_eq!(
+        difference(&at(1, 12, 0, 0), &at(1, 12, 0, 7200))
+            .unwrap()
+            .to_string(),
+        "2 hours ago"
+    );
+    // the end is on the same day on its own clock, but a day and a half hour later
+    let (start, end) = (at(1, 0, 30, 7200), at(1, 23, 0, 0));
+    assert_eq!(
+        difference(&start, &end).unwrap().to_string(),
+        "1 day 30 minutes"
+    );
+    assert_eq!(
+        difference(&end, &start).unwrap().to_string(),
+        "1 day 30 minutes ago"
+    );
+}
+
+#[cfg(feature = "chrono_0_4")]
+#[test]
+fn fixed_offsets() {
+    use chrono::{FixedOffset, TimeZone};
+
+    let at = |offset| {
+        FixedOffset::east_opt(offset)
+            .unwrap()
+            .with_ymd_and_hms(2024, 1, 1, 12, 0, 0)
+            .unwrap()
+    };
+    assert_eq!(
+        difference(&at(7200), &at(0)).unwrap().to_string(),
+        "2 hours"
+    );
+    assert_eq!(difference(&at(0), &at(0)).unwrap().to_string(), "0 seconds");
+}
+
+proptest! {
+    #[test]
+    fn intervals_give_the_end(start in date_time(1900..2100), end in date_time(1900..2100)) {
+        let difference = difference(&start, &end).unwrap();
+        let result = difference
+            .intervals()
+            .iter()
+            .fold(start, |dt, interval: &Interval| interval.add_to(dt));
+        prop_assert_eq!(result, end);
+    }
+}
@@ -1,4 +1,7 @@
-use interim::{parse_duration, DateError, Interval};
+use interim::{
+    datetime::DateTime, parse_date_string, parse_duration, DateError, Dialect, Interval,
+};
+use proptest::prelude::*;
 
 #[test]
 fn acceptance() {
@@ -35,12 +38,479 @@ fn acceptance() {
     assert_duration!("6 months", Interval::Months(6));
     assert_duration!("8 years", Interval::Months(12 * 8));
 
+    assert_duration!("1 year 6 months", Interval::Months(18));
+    assert_duration!("1y6mo ago", Interval::Months(-18));
+    assert_duration!("1 week 3 days", Interval::Days(10));
+    assert_duration!("1h 30m", Interval::Seconds(5400));
+    assert_duration!("-1h30m", Interval::Seconds(-5400));
+    assert_duration!("90 minutes 30 seconds", Interval::Seconds(5430));
+    assert_duration!("86400 seconds", Interval::Seconds(86400));
+    assert_duration!("3000000000 seconds", Interval::Seconds(3_000_000_000));
+    assert_duration!(
+        "9223372036854775808 seconds ago",
+        Interval::Seconds(i64::MIN)
+    );
+
     // errors
     assert_duration_err!("2020-01-01", DateError::UnexpectedAbsoluteDate);
+    assert_duration_err!("@1700000000", DateError::UnexpectedAbsoluteDate);
     assert_duration_err!("2 days 15:00", DateError::UnexpectedTime);
+    assert_duration_err!(
+        "2 days in UTC",
+        DateError::ExpectedToken("end of duration", 10..13)
+    );
+    assert_duration_err!(
+        "2h in UTC",
+        DateError::ExpectedToken("end of duration", 6..9)
+    );
+    assert_duration_err!(
+        "2h to +05:30",
+        DateError::ExpectedToken("end of duration", 6..12)
+    );
     assert_duration_err!("tuesday", DateError::UnexpectedDate);
     assert_duration_err!(
         "bananas",
         DateError::ExpectedToken("unsupported identifier", 0..7)
     );
+    assert_duration_err!(
+        "1 day 2 hours",
+        DateError::ExpectedToken("time unit of the same kind", 8..13)
+    );
+    assert_duration_err!("9223372036854775808 seconds", DateError::Overflow(0..27));
+    assert_duration_err!("3000000000000000 hours", DateError::Overflow(0..22));
+    assert_duration_err!("1 hour 3000000000000000 hours", DateError::Overflow(7..29));
+    assert_duration_err!(
+        "9223372036854775807 seconds 1 second",
+        DateError::Overflow(0..36)
+    );
+    assert_duration_err!("100000000000000000000 seconds", DateError::Overflow(0..21));
+    assert_duration_err!(
+        "12345 bananas",
+        DateError::ExpectedToken("time unit", 6..13)
+    );
+}
+
+#[test]
+fn display() {
+    for (interval, text, compact) in [
+        (Interval::Seconds(0), "0 seconds", "0s"),
+        (Interval::Seconds(1), "1 second", "1s"),
+        (Interval::Seconds(3 * 3600), "3 hours", "3h"),
+        (Interval::Seconds(-5400), "1 hour 30 minutes ago", "-1h30m"),
+        (
+            Interval::Seconds(3661),
+            "1 hour 1 minute 1 second",
+            "1h1m1s",
+        ),
+        (Interval::Days(14), "2 weeks", "2w"),
+        (Interval::Days(-3), "3 days ago", "-3d"),
+        (Interval::Days(10), "1 week 3 days", "1w3d"),
+        (Interval::Months(18), "1 year 6 months", "1y6mo"),
+        (Interval::Months(-1), "1 month ago", "-1mo"),
+        (Interval::Months(0), "0 months", "0mo"),
+    ] {
+        assert_eq!(interval.to_string(), text);
+        assert_eq!(format!("{interval:#}"), compact);
+        assert_eq!(text.parse::<Interval>(), Ok(interval.clone()));
+        assert_eq!(compact.parse::<Interval>(), Ok(interval));
+    }
+}
+
+fn interval() -> impl Strategy<Value = Interval> {
+    prop_oneof![
+        any::<i64>().prop_map(Interval::Seconds),
+        any::<i64>().prop_map(Interval::Days),
+        any::<i64>().prop_map(Interval::Months),
+        (-100_000_i64..100_000).prop_map(Interval::Seconds),
+        (-1000_i64..1000).prop_map(Interval::Days),
+        (-100_i64..100).prop_map(Interval::Months),
+    ]
+}
+
+/// Relative dates with too many digits for a date, and too long for the date-time libraries
+fn long_number() -> impl Strategy<Value = String> {
+    let time = prop::sample::select(vec!["seconds", "minutes", "hours"]);
+    let date = prop::sample::select(vec!["days", "weeks", "months", "years"]);
+    prop_oneof![
+        (10_000_000_000_000..=u64::MAX, time),
+        (100_000_000..=u64::MAX, date),
+    ]
+    .prop_flat_map(|(n, unit)| {
+        prop::sample::select(vec![
+            format!("{n} {unit}"),
+            format!("in {n} {unit}"),
+            format!("{n} {unit} ago"),
+        ])
+    })
+}
+
+/// Parsing the text relative to `now` gives an error rather than panicking
+#[allow(unused)]
+fn out_of_range_text<Dt: DateTime + std::fmt::Debug>(
+    text: &str,
+    now: Dt,
+) -> Result<(), TestCaseError> {
+    let result = parse_date_string(text, now, Dialect::Uk);
+    prop_assert!(result.is_err(), "{:?} gave {:?}", text, result);
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn round_trip(interval in interval()) {
+        prop_assert_eq!(parse_duration(&interval.to_string()), Ok(interval.clone()));
+        prop_assert_eq!(parse_duration(&format!("{interval:#}")), Ok(interval));
+    }
+
+    #[test]
+    fn long_numbers(text in long_number()) {
+        #[cfg(feature = "chrono_0_4")]
+        {
+            use chrono::{TimeZone, Utc};
+
+            out_of_range_text(&text, Utc.with_ymd_and_hms(2023, 1, 30, 9, 30, 0).unwrap())?;
+        }
+        #[cfg(feature = "time_0_3")]
+        out_of_range_text(&text, time::macros::datetime!(2023-01-30 09:30 +01:00))?;
+        #[cfg(feature = "jiff_0_1")]
+        {
+            use jiff_0_1::{tz::TimeZone, Timestamp};
+
+            let now = Timestamp::from_second(1_675_071_000).unwrap();
+            out_of_range_text(&text, now)?;
+            out_of_range_text(&text, now.to_zoned(TimeZone::UTC))?;
+        }
+        #[cfg(feature = "jiff_0_2")]
+        {
+            use jiff_0_2::{tz::TimeZone, Timestamp};
+
+            let now = Timestamp::from_second(1_675_071_000).unwrap();
+            out_of_range_text(&text, now)?;
+            out_of_range_text(&text, now.to_zoned(TimeZone::UTC))?;
+        }
+        // the civil calendar is long enough for some of them, which only mustn't panic
+        let now = interim::civil::DateTime::from_unix(1_675_071_000, 0, 3600).unwrap();
+        let _ = parse_date_string(&text, now, Dialect::Uk);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn std_duration() {
+    use std::time::{Duration, SystemTime};
+
+    assert_eq!(
+        Duration::try_from(Interval::Seconds(90)),
+        Ok(Duration::from_secs(90))
+    );
+    assert_eq!(
+        Duration::try_from(Interval::Seconds(-1)),
+        Err(DateError::UnsupportedDuration)
+    );
+    assert_eq!(
+        Duration::try_from(Interval::Days(1)),
+        Err(DateError::UnsupportedDuration)
+    );
+    assert_eq!(
+        Interval::try_from(Duration::from_secs(3600)),
+        Ok(Interval::Seconds(3600))
+    );
+    assert_eq!(
+        Interval::try_from(Duration::from_millis(1500)),
+        Err(DateError::UnsupportedDuration)
+    );
+
+    // 2024-01-31 09:00 UTC
+    let base = SystemTime::UNIX_EPOCH + Duration::from_secs(1_706_691_600);
+    assert_eq!(
+        Interval::Months(1).std_duration_from(base),
+        Ok(Duration::from_secs(29 * 86400))
+    );
+    assert_eq!(
+        Interval::Days(-1).std_duration_from(base),
+        Err(DateError::UnsupportedDuration)
+    );
+}
+
+#[cfg(feature = "chrono_0_4")]
+#[test]
+fn chrono_duration() {
+    use chrono::{Duration, NaiveDate, TimeZone, Utc};
+
+    assert_eq!(
+        Duration::try_from(Interval::Seconds(-90)),
+        Ok(Duration::seconds(-90))
+    );
+    assert_eq!(
+        Duration::try_from(Interval::Months(1)),
+        Err(DateError::UnsupportedDuration)
+    );
+    assert_eq!(
+        Interval::try_from(Duration::minutes(-5)),
+        Ok(Interval::Seconds(-300))
+    );
+
+    let base = Utc.with_ymd_and_hms(2023, 1, 31, 9, 0, 0).unwrap();
+    assert_eq!(
+        Interval::Months(1).duration_from(base),
+        Ok(Duration::days(28))
+    );
+    assert_eq!(
+        Interval::Months(-12).duration_from(base),
+        Ok(Duration::days(-365))
+    );
+    let base = NaiveDate::from_ymd_opt(2024, 2, 28)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap();
+    assert_eq!(Interval::Days(2).duration_from(base), Ok(Duration::days(2)));
+
+    #[cfg(feature = "chrono_tz_0_10")]
+    {
+        use chrono_tz::Europe::London;
+
+        // the clocks go forward on the 31st of March
+        let base = London.with_ymd_and_hms(2024, 3, 30, 12, 0, 0).unwrap();
+        assert_eq!(
+            Interval::Days(1).duration_from(base),
+            Ok(Duration::hours(23))
+        );
+        assert_eq!(
+            Interval::Seconds(86400).duration_from(base),
+            Ok(Duration::hours(24))
+        );
+    }
+}
+
+#[cfg(feature = "time_0_3")]
+#[test]
+fn time_duration() {
+    use time::macros::datetime;
+    use time::Duration;
+
+    assert_eq!(
+        Duration::try_from(Interval::Seconds(3600)),
+        Ok(Duration::hours(1))
+    );
+    assert_eq!(
+        Duration::try_from(Interval::Days(7)),
+        Err(DateError::UnsupportedDuration)
+    );
+    assert_eq!(
+        Interval::try_from(Duration::milliseconds(10)),
+        Err(DateError::UnsupportedDuration)
+    );
+
+    let base = datetime!(2024-01-31 09:00 +2);
+    assert_eq!(
+        Interval::Months(1).duration_from(base),
+        Ok(Duration::days(29))
+    );
+}
+
+#[cfg(feature = "jiff_0_2")]
+#[test]
+fn jiff_span() {
+    use jiff_0_2::{Span, ToSpan};
+
+    for (interval, span) in [
+        (Interval::Months(18), 18.months()),
+        (Interval::Days(-10), (-10).days()),
+        (Interval::Seconds(5430), 1.hour().minutes(30).seconds(30)),
+    ] {
+        let converted = Span::try_from(interval.clone()).unwrap();
+        assert_eq!(converted.fieldwise(), span.fieldwise());
+        assert_eq!(Interval::try_from(span), Ok(interval));
+    }
+    assert_eq!(
+        Interval::try_from(1.year().months(6)),
+        Ok(Interval::Months(18))
+    );
+    assert_eq!(
+        Interval::try_from(2.weeks().days(1)),
+        Ok(Interval::Days(15))
+    );
+    assert_eq!(
+        Interval::try_from(1.day().hours(2)),
+        Err(DateError::UnsupportedDuration)
+    );
+    assert_eq!(
+        Interval::try_from(1.second().milliseconds(500)),
+        Err(DateError::UnsupportedDuration)
+    );
+    assert_eq!(
+        Span::try_from(Interval::Months(i64::MAX)).map(|span| span.fieldwise()),
+        Err(DateError::UnsupportedDuration)
+    );
+
+    let base: jiff_0_2::Zoned = "2024-03-30T12:00[Europe/London]".parse().unwrap();
+    let span = Interval::Days(1).duration_from(base).unwrap();
+    assert_eq!(span.fieldwise(), 23.hours().fieldwise());
+}
+
+#[test]
+fn arithmetic() {
+    assert_eq!(-Interval::Days(3), Interval::Days(-3));
+    assert_eq!(
+        Interval::Seconds(60).checked_add(&Interval::Seconds(30)),
+        Some(Interval::Seconds(90))
+    );
+    assert_eq!(
+        Interval::Months(12).checked_sub(&Interval::Months(18)),
+        Some(Interval::Months(-6))
+    );
+    assert_eq!(Interval::Days(1).checked_add(&Interval::Months(1)), None);
+    assert_eq!(Interval::Days(1).checked_sub(&Interval::Seconds(1)), None);
+    assert_eq!(
+        Interval::Seconds(i64::MAX).checked_add(&Interval::Seconds(1)),
+        None
+    );
+    assert_eq!(Interval::Months(i64::MIN).checked_neg(), None);
+    assert_eq!(Interval::Days(7).checked_mul(-2), Some(Interval::Days(-14)));
+    assert_eq!(Interval::Days(i64::MAX).checked_mul(2), None);
+}
+
+/// Intervals past the end of every calendar
+const TOO_LONG: [Interval; 6] = [
+    Interval::Seconds(i64::MAX),
+    Interval::Seconds(i64::MIN),
+    Interval::Days(i64::MAX),
+    Interval::Days(i64::MIN),
+    Interval::Months(i64::MAX),
+    Interval::Months(i64::MIN),
+];
+
+/// Intervals past the end of the calendars of the date-time libraries
+#[allow(unused)]
+const TOO_LONG_FOR_LIBRARIES: [Interval; 6] = [
+    Interval::Seconds(9_999_999_999_999),
+    Interval::Seconds(-9_999_999_999_999),
+    Interval::Days(99_999_999),
+    Interval::Days(-99_999_999),
+    Interval::Months(99_999_999),
+    Interval::Months(-99_999_999),
+];
+
+/// Adding or subtracting the intervals gives `None` rather than panicking
+fn out_of_range<Dt: DateTime + Clone + std::fmt::Debug>(base: Dt, intervals: &[Interval]) {
+    for interval in intervals {
+        let added = interval.checked_add_to(base.clone());
+        assert!(added.is_none(), "{interval:?} added gave {added:?}");
+        let subtracted = interval.checked_sub_from(base.clone());
+        assert!(
+            subtracted.is_none(),
+            "{interval:?} subtracted gave {subtracted:?}"
+        );
+    }
+}
+
+#[test]
+fn add_to() {
+    use interim::civil::{Date, DateTime, Time};
+
+    let at = |year, month, day, hour| {
+        let date = Date::new(year, month, day).unwrap();
+        DateTime::from_parts(date, Time::new(hour, 30, 0).unwrap(), 3600).unwrap()
+    };
+
+    let base = at(2023, 1, 30, 9);
+    assert_eq!(Interval::Months(1).add_to(base), at(2023, 2, 28, 9));
+    assert_eq!(Interval::Months(13).add_to(base), at(2024, 2, 29, 9));
+    assert_eq!(Interval::Months(1).sub_from(base), at(2022, 12, 30, 9));
+    assert_eq!(Interval::Days(2).add_to(base), at(2023, 2, 1, 9));
+    assert_eq!(Interval::Days(2).sub_from(base), at(2023, 1, 28, 9));
+    assert_eq!(Interval::Seconds(-3600).add_to(base), at(2023, 1, 30, 8));
+    assert_eq!(Interval::Seconds(3600).sub_from(base), at(2023, 1, 30, 8));
+
+    // months are clamped, so adding and subtracting doesn't give the same date back
+    let end = Interval::Months(1).add_to(at(2023, 3, 31, 9));
+    assert_eq!(Interval::Months(1).sub_from(end), at(2023, 3, 30, 9));
+
+    #[cfg(feature = "chrono_0_4")]
+    {
+        use chrono::{TimeZone, Utc};
+
+        let base = Utc.with_ymd_and_hms(2023, 1, 30, 9, 30, 0).unwrap();
+        assert_eq!(Interval::Months(i64::MAX).checked_add_to(base), None);
+    }
+    assert_eq!(Interval::Days(i64::MIN).checked_sub_from(base), None);
+    assert_eq!(
+        Interval::Days(1).checked_sub_from(base),
+        Some(at(2023, 1, 29, 9))
+    );
+    out_of_range(base, &TOO_LONG);
+
+    #[allow(unused)]
+    let intervals = [TOO_LONG.as_slice(), &TOO_LONG_FOR_LIBRARIES].concat();
+    #[cfg(feature = "chrono_0_4")]
+    {
+        use chrono::{FixedOffset, TimeZone, Utc};
+
+        out_of_range(
+            Utc.with_ymd_and_hms(2023, 1, 30, 9, 30, 0).unwrap(),
+            &intervals,
+        );
+        let offset = FixedOffset::east_opt(3600).unwrap();
+        let base = offset.with_ymd_and_hms(2023, 1, 30, 9, 30, 0).unwrap();
+        out_of_range(base, &intervals);
+        out_of_range(base.naive_local(), &intervals);
+    }
+    #[cfg(feature = "time_0_3")]
+    {
+        let base = time::macros::datetime!(2023-01-30 09:30 +01:00);
+        out_of_range(base, &intervals);
+        out_of_range(base.to_utc(), &intervals);
+        out_of_range(
+            time::PrimitiveDateTime::new(base.date(), base.time()),
+            &intervals,
+        );
+    }
+    #[cfg(feature = "jiff_0_1")]
+    {
+        use jiff_0_1::{tz::TimeZone, Timestamp};
+
+        let base = Timestamp::from_second(1_675_071_000).unwrap();
+        out_of_range(base, &intervals);
+        out_of_range(base.to_zoned(TimeZone::UTC), &intervals);
+        out_of_range(base.to_zoned(TimeZone::UTC).datetime(), &intervals);
+    }
+    #[cfg(feature = "jiff_0_2")]
+    {
+        use jiff_0_2::{tz::TimeZone, Timestamp};
+
+        let base = Timestamp::from_second(1_675_071_000).unwrap();
+        out_of_range(base, &intervals);
+        out_of_range(base.to_zoned(TimeZone::UTC), &intervals);
+        out_of_range(base.to_zoned(TimeZone::UTC).datetime(), &intervals);
+    }
+}
+
+#[cfg(feature = "chrono_tz_0_10")]
+#[test]
+fn add_to_zoned() {
+    use chrono::TimeZone;
+    use chrono_tz::Europe::London;
+
+    // the clocks go forward an hour at 01:00 on the 31st of March
+    let base = London.with_ymd_and_hms(2024, 3, 30, 1, 30, 0).unwrap();
+    assert_eq!(
+        Interval::Days(1).add_to(base),
+        London.with_ymd_and_hms(2024, 3, 31, 2, 30, 0).unwrap()
+    );
+    assert_eq!(
+        Interval::Seconds(86400).add_to(base),
+        London.with_ymd_and_hms(2024, 3, 31, 2, 30, 0).unwrap()
+    );
+    let base = London.with_ymd_and_hms(2024, 3, 30, 12, 0, 0).unwrap();
+    assert_eq!(
+        Interval::Days(1).add_to(base),
+        London.with_ymd_and_hms(2024, 3, 31, 12, 0, 0).unwrap()
+    );
+    assert_eq!(
+        Interval::Seconds(86400).add_to(base),
+        London.with_ymd_and_hms(2024, 3, 31, 13, 0, 0).unwrap()
+    );
+
+    let intervals = [TOO_LONG.as_slice(), &TOO_LONG_FOR_LIBRARIES].concat();
+    out_of_range(base, &intervals);
 }
@@ -0,0 +1,182 @@
+mod common;
+
+use common::{at, date_time};
+use interim::civil::{Date, DateTime, Time};
+use interim::{humanize, humanize_with, parse_date_string, Dialect, HumanizeOptions, Unit};
+use proptest::prelude::*;
+
+#[test]
+fn phrases() {
+    // a Wednesday
+    let now = at(2024, 7, 10, 9, 30, 0);
+    let assert = |dt, options: HumanizeOptions, expected: &str| {
+        let text = humanize_with(&dt, &now, options).unwrap().to_string();
+        assert_eq!(text, expected);
+    };
+    let uk = HumanizeOptions::new(Dialect::Uk);
+    let us = HumanizeOptions::new(Dialect::Us);
+
+    assert(now, uk, "now");
+    assert(at(2024, 7, 10, 9, 29, 31), uk, "now");
+    assert(
+        at(2024, 7, 10, 9, 29, 31),
+        uk.precision(Unit::Second),
+        "29 seconds ago",
+    );
+    assert(at(2024, 7, 10, 9, 35, 0), uk, "in 5 minutes");
+    assert(at(2024, 7, 10, 10, 29, 0), uk, "in 59 minutes");
+    assert(at(2024, 7, 10, 10, 30, 0), uk, "today at 10:30");
+    assert(at(2024, 7, 10, 10, 30, 0), us, "today at 10:30am");
+    assert(
+        at(2024, 7, 10, 10, 30, 0),
+        uk.precision(Unit::Hour),
+        "today at 10am",
+    );
+    assert(at(2024, 7, 10, 0, 0, 0), uk, "today at midnight");
+    assert(at(2024, 7, 9, 17, 0, 0), uk, "yesterday at 5pm");
+    assert(
+        at(2024, 7, 9, 17, 0, 12),
+        uk.precision(Unit::Second),
+        "yesterday at 17:00:12",
+    );
+    assert(
+        at(2024, 7, 9, 17, 0, 12),
+        us.precision(Unit::Second),
+        "yesterday at 5:00:12pm",
+    );
+    assert(
+        at(2024, 7, 11, 8, 0, 0),
+        uk.precision(Unit::Day),
+        "tomorrow",
+    );
+
+    assert(at(2024, 7, 5, 20, 0, 0), uk, "last Friday at 8pm");
+    assert(at(2024, 7, 12, 20, 0, 0), uk, "Friday at 8pm");
+    assert(at(2024, 7, 12, 20, 0, 0), us, "Friday at 8pm");
+    // 'next Friday' is the Friday after this one in the UK, and this one in the US
+    assert(at(2024, 7, 19, 20, 0, 0), uk, "next Friday at 8pm");
+    assert(at(2024, 7, 19, 20, 0, 0), us, "on July 19 at 8pm");
+    // a weekday on its own is the next one after today
+    assert(
+        at(2024, 7, 17, 9, 0, 0),
+        uk.precision(Unit::Day),
+        "Wednesday",
+    );
+    assert(
+        at(2024, 7, 3, 9, 0, 0),
+        uk.precision(Unit::Day),
+        "on 3 July",
+    );
+
+    assert(
+        at(2024, 1, 1, 0, 0, 0),
+        uk.precision(Unit::Day),
+        "on 1 January",
+    );
+    assert(at(2023, 7, 4, 12, 0, 0), uk, "on 4 July 2023 at noon");
+    assert(at(2023, 7, 4, 12, 0, 0), us, "on July 4, 2023 at noon");
+    assert(
+        at(2025, 12, 25, 7, 45, 0),
+        us,
+        "on December 25, 2025 at 7:45am",
+    );
+
+    let text = humanize(&at(2024, 7, 9, 17, 0, 0), &now, Dialect::Us).unwrap();
+    assert_eq!(text.to_string(), "yesterday at 5pm");
+}
+
+#[cfg(feature = "chrono_tz_0_10")]
+#[test]
+fn zoned() {
+    use chrono::TimeZone;
+    use chrono_tz::Europe::London;
+
+    // the clocks go forward an hour at 01:00 on the 31st of March
+    let now = London.with_ymd_and_hms(2024, 3, 30, 12, 0, 0).unwrap();
+    let dt = London.with_ymd_and_hms(2024, 3, 31, 2, 30, 0).unwrap();
+    let text = humanize(&dt, &now, Dialect::Uk).unwrap().to_string();
+    assert_eq!(text, "tomorrow at 02:30");
+    assert_eq!(parse_date_string(&text, now, Dialect::Uk).unwrap(), dt);
+}
+
+#[cfg(feature = "chrono_tz_0_10")]
+#[test]
+fn fold() {
+    use chrono::TimeZone;
+    use chrono_tz::Europe::London;
+
+    // the clocks go back an hour at 02:00 on the 27th of October, so 01:00 to 02:00 happens twice
+    let now = London.with_ymd_and_hms(2024, 10, 27, 0, 30, 0).unwrap();
+    let first = London
+        .with_ymd_and_hms(2024, 10, 27, 1, 30, 0)
+        .earliest()
+        .unwrap();
+    let second = London
+        .with_ymd_and_hms(2024, 10, 27, 1, 30, 0)
+        .latest()
+        .unwrap();
+    let later = London.with_ymd_and_hms(2024, 10, 27, 3, 0, 0).unwrap();
+    for (dt, expected) in [
+        (first, "today at 01:30"),
+        (second, "in 2 hours"),
+        (later, "today at 3am"),
+    ] {
+        let text = humanize(&dt, &now, Dialect::Uk).unwrap().to_string();
+        assert_eq!(text, expected);
+        assert_eq!(parse_date_string(&text, now, Dialect::Uk).unwrap(), dt);
+    }
+}
+
+#[test]
+fn offsets() {
+    let now = DateTime::from_parts(
+        Date::new(2024, 7, 10).unwrap(),
+        Time::new(12, 0, 0).unwrap(),
+        7200,
+    )
+    .unwrap();
+    let at = |hour, minute| {
+        DateTime::from_parts(
+            Date::new(2024, 7, 10).unwrap(),
+            Time::new(hour, minute, 0).unwrap(),
+            0,
+        )
+        .unwrap()
+    };
+    for (dt, expected) in [
+        (at(12, 0), "today at 2pm"),
+        (at(10, 0), "now"),
+        (at(9, 30), "30 minutes ago"),
+        (at(23, 0), "tomorrow at 1am"),
+    ] {
+        let text = humanize(&dt, &now, Dialect::Uk).unwrap().to_string();
+        assert_eq!(text, expected);
+        let parsed = parse_date_string(&text, now, Dialect::Uk).unwrap();
+        assert_eq!(parsed.unix_timestamp(), dt.unix_timestamp(), "{text}");
+    }
+}
+
+proptest! {
+    #[test]
+    fn round_trip(
+        now in date_time(2020..2028),
+        dt in date_time(2020..2028),
+        offset in -20 * 86400..20 * 86400_i64,
+        hours in -12..=14_i32,
+        us in any::<bool>(),
+    ) {
+        let dialect = if us { Dialect::Us } else { Dialect::Uk };
+        let options = HumanizeOptions::new(dialect).precision(Unit::Second);
+        // a date-time far away, and one nearby
+        let near = DateTime::from_unix(now.unix_timestamp() + offset, 0, 3600).unwrap();
+        for dt in [dt, near] {
+            let text = humanize_with(&dt, &now, options).unwrap().to_string();
+            prop_assert_eq!(parse_date_string(&text, now, dialect), Ok(dt), "{}", text);
+        }
+        // and one at another offset, which reads back at the offset of `now`
+        let elsewhere = DateTime::from_unix(now.unix_timestamp() + offset, 0, hours * 3600).unwrap();
+        let text = humanize_with(&elsewhere, &now, options).unwrap().to_string();
+        let parsed = parse_date_string(&text, now, dialect).map(|dt| dt.unix_timestamp());
+        prop_assert_eq!(parsed, Ok(elsewhere.unix_timestamp()), "{}", text);
+    }
+}
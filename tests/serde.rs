@@ -0,0 +1,195 @@
+#![cfg(feature = "serde")]
+
+use interim::{civil, Expression, Interval};
+use serde::{Deserialize, Serialize};
+
+fn round_trip(interval: Interval, text: &str) {
+    let json = serde_json::to_string(&interval).unwrap();
+    assert_eq!(json, format!("{text:?}"));
+    assert_eq!(
+        serde_json::from_str::<Interval>(&json).unwrap(),
+        interval,
+        "{text:?}"
+    );
+}
+
+#[test]
+fn interval() {
+    round_trip(Interval::Seconds(0), "0 seconds");
+    round_trip(Interval::Seconds(1), "1 second");
+    round_trip(Interval::Seconds(90), "1 minute 30 seconds");
+    round_trip(Interval::Seconds(15 * 60), "15 minutes");
+    round_trip(Interval::Seconds(-3600), "1 hour ago");
+    round_trip(Interval::Seconds(36 * 3600), "36 hours");
+    round_trip(Interval::Days(1), "1 day");
+    round_trip(Interval::Days(-14), "2 weeks ago");
+    round_trip(Interval::Days(10), "1 week 3 days");
+    round_trip(Interval::Months(1), "1 month");
+    round_trip(Interval::Months(24), "2 years");
+    round_trip(Interval::Months(-13), "1 year 1 month ago");
+
+    assert_eq!(
+        serde_json::from_str::<Interval>(r#""15m""#).unwrap(),
+        Interval::Seconds(15 * 60)
+    );
+    let err = serde_json::from_str::<Interval>(r#""friday""#).unwrap_err();
+    assert!(err.to_string().contains("expected relative date"), "{err}");
+    let err = serde_json::from_str::<Interval>("15").unwrap_err();
+    assert!(err.to_string().contains("a duration like"), "{err}");
+}
+
+#[test]
+fn expression() {
+    #[derive(Serialize, Deserialize)]
+    struct Schedule {
+        start: Expression,
+        #[serde(with = "interim::serde::us_expression")]
+        holiday: Expression,
+    }
+
+    let json = r#"{"start":"next monday 9am","holiday":"8/11"}"#;
+    let schedule: Schedule = serde_json::from_str(json).unwrap();
+    assert_eq!(schedule.start.as_str(), "next monday 9am");
+    let now = civil::DateTime::from_parts(
+        civil::Date::new(2018, 3, 21).unwrap(),
+        civil::Time::new(11, 0, 0).unwrap(),
+        0,
+    )
+    .unwrap();
+    let holiday: civil::DateTime = schedule.holiday.evaluate(now).unwrap();
+    assert_eq!(holiday.date(), civil::Date::new(2018, 8, 11).unwrap());
+    let uk: Expression = serde_json::from_str(r#""8/11""#).unwrap();
+    let date = uk.evaluate::<civil::DateTime>(now).unwrap().date();
+    assert_eq!(date, civil::Date::new(2018, 11, 8).unwrap());
+    assert_eq!(serde_json::to_string(&schedule).unwrap(), json);
+
+    let err = serde_json::from_str::<Expression>(r#""next blursday""#).unwrap_err();
+    assert!(err.to_string().contains("expected"), "{err}");
+}
+
+#[test]
+fn std_duration() {
+    use std::time::Duration;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "interim::serde::std_duration")]
+        timeout: Duration,
+    }
+
+    let config: Config = serde_json::from_str(r#"{"timeout":"2h"}"#).unwrap();
+    assert_eq!(config.timeout, Duration::from_secs(7200));
+    assert_eq!(
+        serde_json::to_string(&config).unwrap(),
+        r#"{"timeout":"2 hours"}"#
+    );
+    let config: Config = serde_json::from_str(r#"{"timeout":"1 week"}"#).unwrap();
+    assert_eq!(config.timeout, Duration::from_secs(7 * 86400));
+    assert_eq!(
+        serde_json::to_string(&config).unwrap(),
+        r#"{"timeout":"1 week"}"#
+    );
+
+    for (text, message) in [
+        ("3 days ago", "isn't negative"),
+        ("1 month", "fixed length"),
+    ] {
+        let json = format!(r#"{{"timeout":"{text}"}}"#);
+        let err = serde_json::from_str::<Config>(&json).unwrap_err();
+        assert!(err.to_string().contains(message), "{err}");
+    }
+
+    let config = Config {
+        timeout: Duration::from_millis(1500),
+    };
+    assert!(serde_json::to_string(&config).is_err());
+}
+
+#[cfg(feature = "chrono_0_4")]
+#[test]
+fn chrono_duration() {
+    use chrono::TimeDelta;
+
+    #[derive(Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "interim::serde::chrono_duration")]
+        offset: TimeDelta,
+    }
+
+    let config: Config = serde_json::from_str(r#"{"offset":"3 days ago"}"#).unwrap();
+    assert_eq!(config.offset, TimeDelta::days(-3));
+    assert_eq!(
+        serde_json::to_string(&config).unwrap(),
+        r#"{"offset":"3 days ago"}"#
+    );
+    let config = Config {
+        offset: TimeDelta::seconds(5400),
+    };
+    assert_eq!(
+        serde_json::to_string(&config).unwrap(),
+        r#"{"offset":"1 hour 30 minutes"}"#
+    );
+    assert!(serde_json::from_str::<Config>(r#"{"offset":"1 year"}"#).is_err());
+}
+
+#[cfg(feature = "time_0_3")]
+#[test]
+fn time_duration() {
+    use time::Duration;
+
+    #[derive(Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "interim::serde::time_duration")]
+        offset: Duration,
+    }
+
+    let config: Config = serde_json::from_str(r#"{"offset":"-30 minutes"}"#).unwrap();
+    assert_eq!(config.offset, Duration::minutes(-30));
+    assert_eq!(
+        serde_json::to_string(&config).unwrap(),
+        r#"{"offset":"30 minutes ago"}"#
+    );
+    let config = Config {
+        offset: Duration::milliseconds(10),
+    };
+    assert!(serde_json::to_string(&config).is_err());
+}
+
+#[cfg(feature = "jiff_0_2")]
+#[test]
+fn jiff_span() {
+    use jiff_0_2::{Span, ToSpan};
+
+    #[derive(Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "interim::serde::jiff_span")]
+        every: Span,
+    }
+
+    for (text, span, written) in [
+        ("2 months", 2.months(), "2 months"),
+        ("1 week", 7.days(), "1 week"),
+        ("36h", 36.hours(), "36 hours"),
+        ("1 year ago", (-12).months(), "1 year ago"),
+    ] {
+        let json = format!(r#"{{"every":"{text}"}}"#);
+        let config: Config = serde_json::from_str(&json).unwrap();
+        assert_eq!(config.every.fieldwise(), span.fieldwise(), "{text:?}");
+        assert_eq!(
+            serde_json::to_string(&config).unwrap(),
+            format!(r#"{{"every":"{written}"}}"#)
+        );
+    }
+
+    let config = Config {
+        every: 1.hours().minutes(30),
+    };
+    assert_eq!(
+        serde_json::to_string(&config).unwrap(),
+        r#"{"every":"1 hour 30 minutes"}"#
+    );
+    let config = Config {
+        every: 1.month().days(2),
+    };
+    assert!(serde_json::to_string(&config).is_err());
+}